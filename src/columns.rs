@@ -0,0 +1,323 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use calamine::Data;
+use itertools::Itertools;
+use serde::Deserialize;
+
+use crate::xlsx::COLUMNS;
+
+/// The built-in [`ColumnMapping`]s
+/// - `"bsb_tables"`: the column titles of the current `bsb_tables.xlsx`, see [`COLUMNS`]
+/// - `"positions"`: the columns in the order they are published, whatever their titles
+pub const PRESETS: &[&str] = &["bsb_tables", "positions"];
+
+/// Where a field of [`crate::interlinear::RawInterlinearEntry`] is read from
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ColumnRef {
+    /// The column at a (zero based) index, which must not be empty in the header row
+    Index(usize),
+    /// The column with a title, ignoring surrounding whitespace
+    /// - When several fields have the same title, the n-th of them is read from the n-th column with that title
+    Title(String),
+}
+
+impl Display for ColumnRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Index(index) => write!(f, "column {index}"),
+            Self::Title(title) => write!(f, "{}", title.trim()),
+        }
+    }
+}
+
+/// A header row that doesn't have every mapped column, or where two fields would be read from the same column
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnError {
+    /// The columns that weren't found, in field order
+    pub missing: Vec<String>,
+    /// The titles in the row that weren't mapped, which are often the new titles of the missing columns
+    pub unexpected: Vec<String>,
+    /// `[earlier, later]` for each field found in the same column as an earlier field
+    pub duplicates: Vec<[&'static str; 2]>,
+}
+
+impl ColumnError {
+    /// Whichever of the two rows was closer to being the header row
+    pub(crate) fn closest(self, other: Self) -> Self {
+        match other.missing.len() < self.missing.len() {
+            true => other,
+            false => self,
+        }
+    }
+}
+
+impl Display for ColumnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let quoted = |titles: &[String]| titles.iter().map(|title| format!("'{title}'")).join(", ");
+        let duplicates = self.duplicates.iter().map(|[earlier, later]| format!("'{earlier}' and '{later}'")).join(", ");
+        if self.missing.is_empty() {
+            return write!(f, "Fields mapped to the same column: {duplicates}");
+        }
+        write!(f, "Header row not found - missing columns: {}", quoted(&self.missing))?;
+        if !self.unexpected.is_empty() {
+            write!(f, "; unexpected columns: {}", quoted(&self.unexpected))?;
+        }
+        if !self.duplicates.is_empty() {
+            write!(f, "; fields mapped to the same column: {duplicates}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ColumnError {}
+
+/**
+Which column each field of [`crate::interlinear::RawInterlinearEntry`] is read from, since the titles change between releases
+
+A mapping is one of [`PRESETS`], or a JSON override file, which starts from a preset and maps fields to a title or an index:
+```json
+{
+    "preset": "bsb_tables",
+    "sheet": "biblosinterlinear96",
+    "columns": { "text_2": "WLC / Nestle Base", "english": 17 }
+}
+```
+Without a `"preset"`, every field needs a column. The field names are those of [`COLUMNS`].
+
+Columns that aren't mapped are ignored, but are listed in the [`ColumnError`] when the header row isn't found.
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnMapping {
+    /// The sheet to read, instead of the first one with the header row
+    /// - Ignored for TSV and CSV files
+    pub sheet: Option<String>,
+    /// The column of each of [`COLUMNS`], in order
+    columns: Vec<ColumnRef>,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self::preset("bsb_tables").expect("bsb_tables is a preset")
+    }
+}
+
+/// The JSON of an override file
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MappingFile {
+    preset: Option<String>,
+    sheet: Option<String>,
+    #[serde(default)]
+    columns: BTreeMap<String, ColumnRef>,
+}
+
+impl ColumnMapping {
+    /// One of [`PRESETS`]
+    pub fn preset(name: &str) -> Option<Self> {
+        let columns = match name {
+            "bsb_tables" => COLUMNS.iter().map(|(_, title)| ColumnRef::Title(title.to_string())).collect(),
+            "positions" => (0..COLUMNS.len()).map(ColumnRef::Index).collect(),
+            _ => None?,
+        };
+        Some(Self { sheet: None, columns })
+    }
+
+    /// Reads a field from another column
+    pub fn set(&mut self, field: &str, column: ColumnRef) -> Result<(), String> {
+        self.columns[field_index(field)?] = column;
+        Ok(())
+    }
+
+    /// `(field, column)` for every field, in order
+    pub fn columns(&self) -> impl Iterator<Item = (&'static str, &ColumnRef)> {
+        COLUMNS.iter().map(|(field, _)| *field).zip(&self.columns)
+    }
+
+    /// Finds the column of every field in a candidate header row
+    /// - Two fields can't be read from the same column, whether by title or by index
+    pub fn locate(&self, row: &[Data]) -> Result<Vec<usize>, ColumnError> {
+        let mut indexes = Vec::with_capacity(self.columns.len());
+        let mut found_fields = Vec::with_capacity(self.columns.len());
+        let mut missing = Vec::new();
+        let mut duplicates = Vec::new();
+
+        for (i, (field, column)) in self.columns().enumerate() {
+            let found = match column {
+                ColumnRef::Index(index) => row.get(*index)
+                    .filter(|cell| title(cell).is_some() || !matches!(cell, Data::String(_) | Data::Empty))
+                    .map(|_| *index),
+                ColumnRef::Title(expected) => {
+                    let occurrence = self.columns[..i].iter().filter(|other| *other == column).count();
+                    row.iter()
+                        .enumerate()
+                        .filter(|(_, cell)| title(cell) == Some(expected.trim()))
+                        .nth(occurrence)
+                        .map(|(index, _)| index)
+                },
+            };
+            match found {
+                Some(index) => {
+                    if let Some(earlier) = indexes.iter().position(|other| *other == index) {
+                        duplicates.push([found_fields[earlier], field]);
+                    }
+                    indexes.push(index);
+                    found_fields.push(field);
+                },
+                None => missing.push(column.to_string()),
+            }
+        }
+
+        if missing.is_empty() && duplicates.is_empty() {
+            return Ok(indexes);
+        }
+        let unexpected = row.iter()
+            .enumerate()
+            .filter(|(index, _)| !indexes.contains(index))
+            .filter_map(|(_, cell)| title(cell).map(String::from))
+            .collect();
+        Err(ColumnError { missing, unexpected, duplicates })
+    }
+
+    /// The error for a sheet without any rows
+    pub(crate) fn not_found(&self) -> ColumnError {
+        ColumnError { missing: self.columns.iter().map(ColumnRef::to_string).collect(), unexpected: vec![], duplicates: vec![] }
+    }
+}
+
+/// The index of a field in [`COLUMNS`]
+fn field_index(field: &str) -> Result<usize, String> {
+    COLUMNS.iter()
+        .position(|(name, _)| *name == field)
+        .ok_or_else(|| format!("Invalid field - '{field}'"))
+}
+
+/// The trimmed text of a header cell, if it has any
+fn title(cell: &Data) -> Option<&str> {
+    match cell {
+        Data::String(text) => Some(text.trim()).filter(|text| !text.is_empty()),
+        _ => None,
+    }
+}
+
+impl FromStr for ColumnMapping {
+    type Err = String;
+
+    /// Parses a JSON override file
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let file: MappingFile = serde_json::from_str(s).map_err(|error| format!("Invalid column mapping - {error}"))?;
+        let mut columns = match &file.preset {
+            Some(preset) => Self::preset(preset)
+                .ok_or_else(|| format!("Invalid preset - '{preset}', expected one of {}", PRESETS.join(", ")))?
+                .columns
+                .into_iter()
+                .map(Some)
+                .collect(),
+            None => vec![None; COLUMNS.len()],
+        };
+        for (field, column) in file.columns {
+            columns[field_index(&field)?] = Some(column);
+        }
+
+        let unmapped = COLUMNS.iter()
+            .zip(&columns)
+            .filter(|(_, column)| column.is_none())
+            .map(|((field, _), _)| *field)
+            .collect_vec();
+        if !unmapped.is_empty() {
+            Err(format!("Fields without a column - {}", unmapped.join(", ")))?
+        }
+        Ok(Self { sheet: file.sheet, columns: columns.into_iter().flatten().collect() })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{delimited::{raw_entries_from_delimited, Delimiter}, fixture::{fixture_path, raw_fixture, tsv_fixture_path}, stream::RawEntryReader, xlsx::{read_raw_entries_with, ReadError}};
+
+    use super::*;
+
+    #[test]
+    fn presets() -> Result<(), ReadError> {
+        let expected = raw_fixture();
+        for preset in PRESETS {
+            let mapping = ColumnMapping::preset(preset).unwrap();
+            assert_eq!(read_raw_entries_with(fixture_path(), &mapping)?, expected, "{preset}");
+        }
+        assert_eq!(ColumnMapping::preset("z"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn renamed() -> Result<(), ReadError> {
+        let tsv = std::fs::read_to_string(tsv_fixture_path())?.replacen("\tTranslit\t", "\tTransliteration\t", 1);
+        match raw_entries_from_delimited(&tsv, Delimiter::Tab, &ColumnMapping::default()) {
+            Err(ReadError::Columns(error)) => {
+                assert_eq!(error, ColumnError { missing: vec!["Translit".to_string()], unexpected: vec!["Transliteration".to_string()], duplicates: vec![] });
+                assert_eq!(error.to_string(), "Header row not found - missing columns: 'Translit'; unexpected columns: 'Transliteration'");
+            },
+            other => panic!("Expected a column error, got {:?}", other),
+        }
+
+        let mapping = r#"{ "preset": "bsb_tables", "columns": { "transliteration": "Transliteration" } }"#.parse::<ColumnMapping>().unwrap();
+        assert_eq!(raw_entries_from_delimited(&tsv, Delimiter::Tab, &mapping)?, raw_fixture());
+        Ok(())
+    }
+
+    #[test]
+    fn override_file() {
+        let mapping = r#"{ "preset": "positions", "sheet": "biblosinterlinear96", "columns": { "text_2": "WLC", "english": 18 } }"#.parse::<ColumnMapping>().unwrap();
+        assert_eq!(mapping.sheet.as_deref(), Some("biblosinterlinear96"));
+        let columns = mapping.columns().collect_vec();
+        assert_eq!(columns[0], ("hebrew_sort", &ColumnRef::Index(0)));
+        assert_eq!(columns[6], ("text_2", &ColumnRef::Title("WLC".to_string())));
+        assert_eq!(columns[17], ("english", &ColumnRef::Index(18)));
+
+        let all = format!("{{ \"columns\": {{ {} }} }}", COLUMNS.iter().enumerate().map(|(index, (field, _))| format!("\"{field}\": {index}")).join(", "));
+        assert_eq!(all.parse(), Ok(ColumnMapping::preset("positions").unwrap()));
+
+        assert_eq!(
+            r#"{ "columns": { "hebrew_sort": 0 } }"#.parse::<ColumnMapping>().unwrap_err(),
+            format!("Fields without a column - {}", COLUMNS[1..].iter().map(|(field, _)| *field).join(", "))
+        );
+        assert_eq!(r#"{ "preset": "2019" }"#.parse::<ColumnMapping>().unwrap_err(), "Invalid preset - '2019', expected one of bsb_tables, positions");
+        assert_eq!(r#"{ "columns": { "strongs": 10 } }"#.parse::<ColumnMapping>().unwrap_err(), "Invalid field - 'strongs'");
+        assert!(r#"{ "presets": "bsb_tables" }"#.parse::<ColumnMapping>().unwrap_err().starts_with("Invalid column mapping - unknown field `presets`"));
+    }
+
+    #[test]
+    fn locate() {
+        let mapping = ColumnMapping::preset("positions").unwrap();
+        // the title row above the header only has its first cell
+        let title = [Data::String("Berean Standard Bible Translation Tables".to_string())];
+        let error = mapping.locate(&title).unwrap_err();
+        assert_eq!(error.missing[..2], ["column 1", "column 2"]);
+        assert_eq!(error.unexpected, Vec::<String>::new());
+    }
+
+    #[test]
+    fn duplicates() -> Result<(), ReadError> {
+        // `transliteration` is read from the "Translit" column by its title
+        let mut mapping = ColumnMapping::default();
+        let translit = ColumnMapping::preset("positions").unwrap().columns().find(|(field, _)| *field == "transliteration").unwrap().1.clone();
+        mapping.set("english", translit).unwrap();
+
+        let expected = ColumnError { missing: vec![], unexpected: vec!["BSB version".to_string()], duplicates: vec![["transliteration", "english"]] };
+        let tsv = std::fs::read_to_string(tsv_fixture_path())?;
+        let errors = [
+            read_raw_entries_with(fixture_path(), &mapping).map(|_| ()),
+            raw_entries_from_delimited(&tsv, Delimiter::Tab, &mapping).map(|_| ()),
+            RawEntryReader::open(fixture_path())?.with_columns(mapping.clone()).rows().map(|_| ()),
+        ];
+        for error in errors {
+            match error {
+                Err(ReadError::Columns(error)) => {
+                    assert_eq!(error, expected);
+                    assert_eq!(error.to_string(), "Fields mapped to the same column: 'transliteration' and 'english'");
+                },
+                other => panic!("Expected a column error, got {:?}", other),
+            }
+        }
+        Ok(())
+    }
+}
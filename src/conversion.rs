@@ -0,0 +1,275 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{books::{locate_reference, locate_verse_id, verse_id_reference}, interlinear::{InterlinearWordEntry, Language, RawInterlinearEntry, TranslatedWord}};
+
+/// `<a href =|../ruth/4.htm#18|>Ruth 4:18–22</a>` captures `Ruth 4:18–22`
+static CROSSREF_ANCHOR: Lazy<Regex> = Lazy::new(|| Regex::new(r"<a [^>]*>(.*?)</a>").unwrap());
+
+static HTML_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+/// A cell that could not be converted
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionError {
+    /// Excel Column `"BSB Sort"` of the row
+    pub bsb_sort: u32,
+    /// Excel column title of the cell
+    pub column: &'static str,
+    pub reason: String,
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BSB Sort {}, column \"{}\": {}", self.bsb_sort, self.column, self.reason)
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// A cell that was invalid but could be recovered from the rest of the row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionWarning {
+    /// Excel Column `"BSB Sort"` of the row
+    pub bsb_sort: u32,
+    /// Excel column title of the cell
+    pub column: &'static str,
+    pub reason: String,
+}
+
+impl Display for ConversionWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BSB Sort {}, column \"{}\": {}", self.bsb_sort, self.column, self.reason)
+    }
+}
+
+/// How an invalid `"Language"` cell is handled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LanguageMode {
+    /// The row fails to convert
+    #[default]
+    Strict,
+    /// The language is inferred with [`Language::infer`] and a [`ConversionWarning`] is emitted
+    Infer,
+}
+
+impl TryFrom<RawInterlinearEntry> for InterlinearWordEntry {
+    type Error = ConversionError;
+
+    fn try_from(raw: RawInterlinearEntry) -> Result<Self, Self::Error> {
+        Self::convert(raw, LanguageMode::Strict).map(|(word, _)| word)
+    }
+}
+
+impl InterlinearWordEntry {
+    /// Converts a row, returning a warning for each cell that had to be recovered
+    pub fn convert(raw: RawInterlinearEntry, mode: LanguageMode) -> Result<(Self, Vec<ConversionWarning>), ConversionError> {
+        let error = |column: &'static str, reason: String| ConversionError { bsb_sort: raw.bsb_sort, column, reason };
+        let mut warnings = vec![];
+
+        let verse = locate_verse_id(raw.verse_id).ok_or_else(|| error("Verse", format!("Invalid verse id - '{}'", raw.verse_id)))?;
+        if let Some(reference) = raw.verse.as_deref()
+            && locate_reference(reference).map_err(|reason| error("Verse", reason))? != verse
+        {
+            let decoded = verse_id_reference(raw.verse_id).unwrap_or_default();
+            Err(error("Verse", format!("Verse id {} is '{decoded}' but the reference is '{reference}'", raw.verse_id)))?;
+        }
+
+        let language = match (raw.language.parse::<Language>(), mode) {
+            (Ok(language), _) => language,
+            (Err(reason), LanguageMode::Strict) => Err(error("Language", reason))?,
+            (Err(reason), LanguageMode::Infer) => {
+                let book = locate_verse_id(raw.verse_id).map(|(book, _, _)| book);
+                let language = Language::infer(raw.strongs_hebrew, raw.strongs_greek, book, raw.text_1.as_deref().unwrap_or_default())
+                    .ok_or_else(|| error("Language", format!("{reason}, and the language could not be inferred")))?;
+                warnings.push(ConversionWarning { bsb_sort: raw.bsb_sort, column: "Language", reason: format!("{reason}, inferred {language}") });
+                language
+            },
+        };
+
+        let Ok(english) = raw.english.as_deref()
+            .ok_or_else(|| error(" BSB version ", "Translation is missing".to_string()))?
+            .parse::<TranslatedWord>();
+
+        let crossref = non_empty(raw.crossref)
+            .map(|cell| crossrefs(&cell).ok_or_else(|| error("Crossref", format!("No cross references in '{cell}'"))))
+            .transpose()?;
+
+        let word = Self {
+            hebrew_sort: raw.hebrew_sort,
+            greek_sort: raw.greek_sort,
+            bsb_sort: raw.bsb_sort,
+            verse_id: raw.verse_id,
+            language,
+            text_1: raw.text_1.unwrap_or_default(),
+            text_2: raw.text_2.unwrap_or_default(),
+            transliteration: raw.transliteration.unwrap_or_default(),
+            parsing_code: raw.parsing_code.unwrap_or_default(),
+            parsing: raw.parsing.unwrap_or_default(),
+            strongs_hebrew: raw.strongs_hebrew,
+            strongs_greek: raw.strongs_greek,
+            heading: non_empty(raw.heading).and_then(|cell| heading(&cell)),
+            crossref,
+            paragraph: non_empty(raw.paragraph).map(|cell| html(&cell)),
+            start_quote: non_empty(raw.start_quote).map(|cell| html(&cell)),
+            english,
+            puncutation: non_empty(raw.puncutation).map(|cell| html(&cell)),
+            end_quote: non_empty(raw.end_quote).map(|cell| html(&cell)),
+            footnotes: non_empty(raw.footnotes).map(|cell| html(&cell)),
+            end_text: non_empty(raw.end_text).map(|cell| html(&cell)),
+        };
+
+        Ok((word, warnings))
+    }
+}
+
+/// Converts every row, collecting the rows that failed instead of stopping at the first one
+/// - The verse comes from the numeric `"Verse"` column, since only the first word of a verse has the `"Verse"` reference
+/// - Rows that do have the reference are cross-checked against the verse id, and a mismatch is reported as an error
+pub fn convert_entries(raws: Vec<RawInterlinearEntry>) -> (Vec<InterlinearWordEntry>, Vec<ConversionError>) {
+    raws.into_iter()
+        .map(InterlinearWordEntry::try_from)
+        .partition_result()
+}
+
+/// [`convert_entries`], recovering invalid cells according to `mode` and collecting the warnings
+pub fn convert_entries_with(raws: Vec<RawInterlinearEntry>, mode: LanguageMode) -> (Vec<InterlinearWordEntry>, Vec<ConversionError>, Vec<ConversionWarning>) {
+    let mut warnings = vec![];
+    let (words, errors) = raws.into_iter()
+        .map(|raw| InterlinearWordEntry::convert(raw, mode))
+        .map_ok(|(word, word_warnings)| {
+            warnings.extend(word_warnings);
+            word
+        })
+        .partition_result();
+    (words, errors, warnings)
+}
+
+fn non_empty(cell: Option<String>) -> Option<String> {
+    cell.filter(|cell| !cell.is_empty())
+}
+
+/// HTML Markup uses `|` instead of `"`
+fn html(cell: &str) -> String {
+    cell.replace('|', "\"")
+}
+
+/// `"<p class=|hdg|>The Genealogy of Jesus"` is really `"The Genealogy of Jesus"`
+fn heading(cell: &str) -> Option<String> {
+    let text = HTML_TAG.replace_all(cell, "");
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// `"<br /><span class=|cross|>(<a href =|../ruth/4.htm#18|>Ruth 4:18–22</a>; <a href =|../luke/3.htm#23|>Luke 3:23–38</a>)</span>"`
+/// is really `["Ruth 4:18–22", "Luke 3:23–38"]`
+fn crossrefs(cell: &str) -> Option<Vec<String>> {
+    let references = CROSSREF_ANCHOR.captures_iter(cell)
+        .map(|captures| captures[1].trim().to_string())
+        .collect_vec();
+    (!references.is_empty()).then_some(references)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{fixture::raw_fixture, interlinear::TranslatedTextSegment};
+
+    use super::*;
+
+    #[test]
+    fn bsb() {
+        let (words, errors) = convert_entries(raw_fixture());
+        assert_eq!(errors, vec![]);
+        assert_eq!(words.len(), 62);
+
+        let record = words.iter().find(|word| word.text_1 == "Βίβλος").unwrap();
+        assert_eq!(locate_verse_id(record.verse_id), Some((40, 1, 1)));
+        assert_eq!(record.language, Language::Greek);
+        assert_eq!(record.heading.as_deref(), Some("The Genealogy of Jesus"));
+        assert_eq!(record.crossref, Some(vec!["Ruth 4:18–22".to_string(), "Luke 3:23–38".to_string()]));
+        assert_eq!(record.paragraph.as_deref(), Some("<p class=\"reg\">"));
+        assert_eq!(record.english, TranslatedWord::Text(vec![
+            TranslatedTextSegment::Space(" ".to_string()),
+            TranslatedTextSegment::Grammar("This is the".to_string()),
+            TranslatedTextSegment::Space(" ".to_string()),
+            TranslatedTextSegment::Word("record".to_string()),
+            TranslatedTextSegment::Space(" ".to_string()),
+        ]));
+
+        // the reference is shared with the rest of the verse
+        let abraham = words.iter().find(|word| word.text_1 == "Ἀβραάμ").unwrap();
+        assert_eq!(locate_verse_id(abraham.verse_id), Some((40, 1, 1)));
+        assert_eq!(abraham.heading, None);
+        assert_eq!(abraham.puncutation.as_deref(), Some(":"));
+
+        let jegar = words.iter().find(|word| word.transliteration == "yə·ḡar").unwrap();
+        assert_eq!(locate_verse_id(jegar.verse_id), Some((1, 31, 47)));
+        assert_eq!(jegar.language, Language::Aramaic);
+
+        let marker = |transliteration: &str| words.iter().find(|word| word.transliteration == transliteration).unwrap().english.clone();
+        assert_eq!(marker("’êṯ"), TranslatedWord::Omitted);
+        assert_eq!(marker("śā·hă·ḏū·ṯā"), TranslatedWord::Earlier);
+        assert_eq!(marker("way·yō·mer"), TranslatedWord::Later);
+    }
+
+    #[test]
+    fn bad_cells() {
+        let mut raws = raw_fixture();
+        raws[3].language = "z".to_string();
+        raws[5].crossref = Some("<br />".to_string());
+        raws[6].english = None;
+        let (words, errors) = convert_entries(raws.clone());
+
+        assert_eq!(words.len(), 59);
+        assert_eq!(errors, vec![
+            ConversionError { bsb_sort: raws[3].bsb_sort, column: "Language", reason: "Invalid Language - 'z'".to_string() },
+            ConversionError { bsb_sort: raws[5].bsb_sort, column: "Crossref", reason: "No cross references in '<br />'".to_string() },
+            ConversionError { bsb_sort: raws[6].bsb_sort, column: " BSB version ", reason: "Translation is missing".to_string() },
+        ]);
+    }
+
+    /// `errors.md`: element `92071` has `"z"` as the language, instead of `"Hebrew"`
+    #[test]
+    fn invalid_language() {
+        let mut raws = raw_fixture();
+        raws[3].language = "z".to_string();
+        raws[40].language = "z".to_string();
+        // no Strong's number, and the text is not Hebrew
+        raws[4].language = "z".to_string();
+        raws[4].strongs_hebrew = None;
+        raws[4].text_1 = Some("Βίβλος".to_string());
+
+        let (words, errors, warnings) = convert_entries_with(raws.clone(), LanguageMode::Infer);
+        assert_eq!(words.len(), 61);
+        assert_eq!(errors, vec![
+            ConversionError { bsb_sort: raws[4].bsb_sort, column: "Language", reason: "Invalid Language - 'z', and the language could not be inferred".to_string() },
+        ]);
+        assert_eq!(warnings, vec![
+            ConversionWarning { bsb_sort: raws[3].bsb_sort, column: "Language", reason: "Invalid Language - 'z', inferred Hebrew".to_string() },
+            ConversionWarning { bsb_sort: raws[40].bsb_sort, column: "Language", reason: "Invalid Language - 'z', inferred Greek".to_string() },
+        ]);
+        assert_eq!(words[3].language, Language::Hebrew);
+        assert_eq!(words[39].language, Language::Greek);
+
+        let (words, errors, warnings) = convert_entries_with(raws, LanguageMode::Strict);
+        assert_eq!((words.len(), errors.len(), warnings.len()), (59, 3, 0));
+    }
+
+    #[test]
+    fn verse_mismatch() {
+        let mut raws = raw_fixture();
+        raws[0].verse = Some("Genesis 1:2".to_string());
+        raws[1].verse_id = 0;
+        raws[8].verse = Some("Genesis 1:2".to_string());
+        let (words, errors) = convert_entries(raws.clone());
+
+        assert_eq!(words.len(), 60);
+        assert_eq!(errors, vec![
+            ConversionError { bsb_sort: raws[0].bsb_sort, column: "Verse", reason: "Verse id 1 is 'Genesis 1:1' but the reference is 'Genesis 1:2'".to_string() },
+            ConversionError { bsb_sort: raws[1].bsb_sort, column: "Verse", reason: "Invalid verse id - '0'".to_string() },
+        ]);
+        assert_eq!(locate_verse_id(words[5].verse_id), Some((1, 1, 2)));
+    }
+}
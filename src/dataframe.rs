@@ -0,0 +1,153 @@
+use itertools::Itertools;
+use polars::prelude::*;
+
+use crate::{books::locate_verse_id, greek::{components::ComponentCode, word::GreekWordParsing}, hebrew_parsing::HebrewWordParsing, interlinear::{InterlinearWordEntry, Language}};
+
+/// The categorical morphology columns, in order
+pub const MORPHOLOGY_COLUMNS: [&str; 9] = ["part_of_speech", "tense", "voice", "mood", "case", "number", "gender", "person", "comparison"];
+
+/// The `code_name` of each of [`MORPHOLOGY_COLUMNS`] for a word
+/// - Hebrew and Aramaic words only have a part of speech, number, gender and person, named like the Greek ones so both languages group together
+/// - A `"Parsing"` code that can't be parsed leaves every column empty (see [`crate::report::QualityReport`])
+pub(crate) fn morphology(word: &InterlinearWordEntry) -> [Option<&'static str>; 9] {
+    match word.language {
+        Language::Greek => GreekWordParsing::parse(&word.parsing_code)
+            .map(|parsing| [
+                Some(parsing.part_of_speech().code_name()),
+                parsing.tense().map(|c| c.code_name()),
+                parsing.voice().map(|c| c.code_name()),
+                parsing.mood().map(|c| c.code_name()),
+                parsing.case().map(|c| c.code_name()),
+                parsing.number().map(|c| c.code_name()),
+                parsing.gender().map(|c| c.code_name()),
+                parsing.person().map(|c| c.code_name()),
+                parsing.comparison().map(|c| c.code_name()),
+            ])
+            .unwrap_or_default(),
+        Language::Hebrew | Language::Aramaic => HebrewWordParsing::parse(&word.parsing_code)
+            .map(|parsing| [
+                Some(parsing.word.part_of_speech()),
+                None,
+                None,
+                None,
+                None,
+                parsing.number().map(|c| c.code_name()),
+                parsing.gender().map(|c| c.code_name()),
+                parsing.person().map(|c| c.code_name()),
+                None,
+            ])
+            .unwrap_or_default(),
+    }
+}
+
+fn categorical(name: &str, values: &[Option<&str>]) -> PolarsResult<Column> {
+    Series::new(name.into(), values)
+        .cast(&DataType::Categorical(None, CategoricalOrdering::Lexical))
+        .map(Column::from)
+}
+
+/**
+Every word as a row, so that it can be filtered and grouped without parsing the workbook again
+
+| Column | Type |
+| --- | --- |
+| `hebrew_sort`, `greek_sort`, `bsb_sort`, `verse_id` | `u32` |
+| `book`, `chapter`, `verse` | `u32` |
+| `language` | categorical |
+| `text`, `transliteration`, `parsing_code` | string |
+| `strongs_hebrew`, `strongs_greek` | `u32`, or null |
+| `english` | string, the `" BSB version "` cell without its padding |
+| `part_of_speech`, `tense`, `voice`, `mood`, `case`, `number`, `gender`, `person`, `comparison` | categorical, or null when the word doesn't have it |
+
+`book`, `chapter` and `verse` are located from the `verse_id`, which is an error when it isn't a verse of the BSB.
+*/
+pub fn words_dataframe(words: &[InterlinearWordEntry]) -> PolarsResult<DataFrame> {
+    let u32_column = |name: &str, value: fn(&InterlinearWordEntry) -> u32| Column::new(name.into(), words.iter().map(value).collect_vec());
+    let language = words.iter().map(|word| word.language.to_string()).collect_vec();
+    let english = words.iter().map(|word| word.english.to_string().trim().to_string()).collect_vec();
+    let morphology = words.iter().map(morphology).collect_vec();
+    let verses = words.iter()
+        .map(|word| locate_verse_id(word.verse_id).ok_or_else(|| PolarsError::ComputeError(format!("Invalid verse id - '{}'", word.verse_id).into())))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let mut columns = vec![
+        u32_column("hebrew_sort", |word| word.hebrew_sort),
+        u32_column("greek_sort", |word| word.greek_sort),
+        u32_column("bsb_sort", |word| word.bsb_sort),
+        u32_column("verse_id", |word| word.verse_id),
+        Column::new("book".into(), verses.iter().map(|(book, _, _)| *book as u32).collect_vec()),
+        Column::new("chapter".into(), verses.iter().map(|(_, chapter, _)| *chapter as u32).collect_vec()),
+        Column::new("verse".into(), verses.iter().map(|(_, _, verse)| *verse as u32).collect_vec()),
+        categorical("language", &language.iter().map(|language| Some(language.as_str())).collect_vec())?,
+        Column::new("text".into(), words.iter().map(|word| word.text_1.as_str()).collect_vec()),
+        Column::new("transliteration".into(), words.iter().map(|word| word.transliteration.as_str()).collect_vec()),
+        Column::new("parsing_code".into(), words.iter().map(|word| word.parsing_code.as_str()).collect_vec()),
+        Column::new("strongs_hebrew".into(), words.iter().map(|word| word.strongs_hebrew).collect_vec()),
+        Column::new("strongs_greek".into(), words.iter().map(|word| word.strongs_greek).collect_vec()),
+        Column::new("english".into(), english),
+    ];
+    for (index, name) in MORPHOLOGY_COLUMNS.iter().enumerate() {
+        columns.push(categorical(name, &morphology.iter().map(|values| values[index]).collect_vec())?);
+    }
+
+    DataFrame::new(columns)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fixture::word_fixture;
+
+    use super::*;
+
+    /// The value of a column in the row of a word
+    fn cell(df: &DataFrame, column: &str, row: usize) -> PolarsResult<Option<String>> {
+        let column = df.column(column)?.cast(&DataType::String)?;
+        Ok(column.str()?.get(row).map(String::from))
+    }
+
+    #[test]
+    fn bsb() -> PolarsResult<()> {
+        let words = word_fixture();
+        let df = words_dataframe(&words)?;
+        assert_eq!(df.shape(), (62, 23));
+        assert_eq!(df.column("language")?.dtype(), &DataType::Categorical(None, CategoricalOrdering::Lexical));
+        assert_eq!(df.column("language")?.n_unique()?, 3);
+
+        // In the beginning
+        assert_eq!(cell(&df, "part_of_speech", 0)?.as_deref(), Some("Noun"));
+        assert_eq!(cell(&df, "gender", 0)?.as_deref(), Some("Feminine"));
+        assert_eq!(cell(&df, "tense", 0)?, None);
+        assert_eq!(cell(&df, "english", 0)?.as_deref(), Some("In the beginning"));
+        assert_eq!(df.column("strongs_hebrew")?.u32()?.get(0), Some(7225));
+        assert_eq!(df.column("strongs_greek")?.u32()?.get(0), None);
+
+        // This is the record
+        let record = words.iter().position(|word| word.text_1 == "Βίβλος").unwrap();
+        assert_eq!(df.column("book")?.u32()?.get(record), Some(40));
+        assert_eq!(df.column("chapter")?.u32()?.get(record), Some(1));
+        assert_eq!(cell(&df, "language", record)?.as_deref(), Some("Greek"));
+        assert_eq!(cell(&df, "part_of_speech", record)?.as_deref(), Some("Noun"));
+        assert_eq!(cell(&df, "case", record)?.as_deref(), Some("Nominative"));
+        assert_eq!(cell(&df, "number", record)?.as_deref(), Some("Singular"));
+        assert_eq!(cell(&df, "english", record)?.as_deref(), Some("[This is the] record"));
+
+        // every Greek verb has a tense
+        let greek = df.column("language")?.cast(&DataType::String)?;
+        let part_of_speech = df.column("part_of_speech")?.cast(&DataType::String)?;
+        let tense = df.column("tense")?;
+        let verbs = (0..df.height())
+            .filter(|row| greek.str().unwrap().get(*row) == Some("Greek") && part_of_speech.str().unwrap().get(*row) == Some("Verb"))
+            .collect_vec();
+        assert!(!verbs.is_empty());
+        assert!(verbs.iter().all(|row| !tense.get(*row).unwrap().is_null()));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_verse_id() {
+        let mut words = word_fixture();
+        words[1].verse_id = 99001001;
+        let error = words_dataframe(&words).unwrap_err();
+        assert!(error.to_string().contains("Invalid verse id - '99001001'"), "{error}");
+    }
+}
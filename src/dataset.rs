@@ -0,0 +1,267 @@
+use std::{fmt::Display, fs::File, path::Path};
+
+use itertools::Itertools;
+use polars::prelude::*;
+
+use crate::{books::decode_verse_id, conversion::ConversionError, dataframe::words_dataframe, interlinear::{InterlinearVerseEntry, InterlinearWordEntry, Language, TranslatedWord, WordOrder}};
+
+/// Written to every row of the `schema_version` column
+/// - Bumped whenever a column is added, removed, renamed or changes type, so that old files are rejected instead of misread
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The file format of a dataset
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DatasetFormat {
+    /// Apache Parquet: smaller files, for storage and exchange
+    Parquet,
+    /// Arrow IPC (Feather v2): larger files that load faster
+    Ipc,
+}
+
+impl DatasetFormat {
+    /// `.parquet`, or `.arrow`, `.ipc` and `.feather`
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_lowercase();
+        Some(match extension.as_str() {
+            "parquet" => Self::Parquet,
+            "arrow" | "ipc" | "feather" => Self::Ipc,
+            _ => None?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum DatasetError {
+    Io(std::io::Error),
+    Polars(PolarsError),
+    /// The file has another [`SCHEMA_VERSION`], or no `schema_version` column
+    Version(Option<u32>),
+    /// A row whose cells can't be read back, where the column is the Excel column title
+    Conversion(ConversionError),
+}
+
+impl Display for DatasetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{}", error),
+            Self::Polars(error) => write!(f, "{}", error),
+            Self::Version(Some(version)) => write!(f, "Dataset schema version {version} is not supported, expected {SCHEMA_VERSION}"),
+            Self::Version(None) => write!(f, "Dataset has no schema version"),
+            Self::Conversion(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for DatasetError {}
+
+impl From<std::io::Error> for DatasetError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<PolarsError> for DatasetError {
+    fn from(error: PolarsError) -> Self {
+        Self::Polars(error)
+    }
+}
+
+/**
+Every word as a row, with every cell needed to read it back
+- `schema_version`: [`SCHEMA_VERSION`]
+- The columns of [`words_dataframe`], including the parsed morphology
+- `text_2`, `parsing`, `heading`, `paragraph`, `start_quote`, `puncutation`, `end_quote`, `footnotes` and `end_text`: string, or null
+- `crossref`: list of strings, or null
+- `english_cell`: the `" BSB version "` cell, with its padding
+
+Verses aren't stored separately, since each word has its `verse_id`
+*/
+pub fn dataset_dataframe(words: &[InterlinearWordEntry]) -> PolarsResult<DataFrame> {
+    let optional = |name: &str, value: fn(&InterlinearWordEntry) -> &Option<String>| {
+        Column::new(name.into(), words.iter().map(|word| value(word).as_deref()).collect_vec())
+    };
+    let crossref = words.iter()
+        .map(|word| word.crossref.as_ref().map(|references| Series::new(PlSmallStr::EMPTY, references)))
+        .collect::<ListChunked>()
+        .with_name("crossref".into())
+        .cast(&DataType::List(Box::new(DataType::String)))?;
+
+    let mut columns = vec![Column::new("schema_version".into(), vec![SCHEMA_VERSION; words.len()])];
+    columns.extend(words_dataframe(words)?.take_columns());
+    columns.extend([
+        Column::new("text_2".into(), words.iter().map(|word| word.text_2.as_str()).collect_vec()),
+        Column::new("parsing".into(), words.iter().map(|word| word.parsing.as_str()).collect_vec()),
+        optional("heading", |word| &word.heading),
+        crossref.into_column(),
+        optional("paragraph", |word| &word.paragraph),
+        optional("start_quote", |word| &word.start_quote),
+        Column::new("english_cell".into(), words.iter().map(|word| word.english.to_string()).collect_vec()),
+        optional("puncutation", |word| &word.puncutation),
+        optional("end_quote", |word| &word.end_quote),
+        optional("footnotes", |word| &word.footnotes),
+        optional("end_text", |word| &word.end_text),
+    ]);
+    DataFrame::new(columns)
+}
+
+fn numbers<'a>(df: &'a DataFrame, name: &str) -> PolarsResult<&'a UInt32Chunked> {
+    df.column(name)?.u32()
+}
+
+/// Categorical columns are read as their values
+fn strings(df: &DataFrame, name: &str) -> PolarsResult<StringChunked> {
+    Ok(df.column(name)?.cast(&DataType::String)?.str()?.clone())
+}
+
+/// The inverse of [`dataset_dataframe`]
+/// - The morphology columns are ignored, since they are parsed from `parsing_code`
+pub fn words_from_dataframe(df: &DataFrame) -> Result<Vec<InterlinearWordEntry>, DatasetError> {
+    let versions = df.column("schema_version").map_err(|_| DatasetError::Version(None))?.u32()?;
+    if let Some(version) = versions.iter().find(|version| *version != Some(SCHEMA_VERSION)) {
+        Err(DatasetError::Version(version))?
+    }
+
+    let hebrew_sort = numbers(df, "hebrew_sort")?;
+    let greek_sort = numbers(df, "greek_sort")?;
+    let bsb_sort = numbers(df, "bsb_sort")?;
+    let verse_id = numbers(df, "verse_id")?;
+    let strongs_hebrew = numbers(df, "strongs_hebrew")?;
+    let strongs_greek = numbers(df, "strongs_greek")?;
+    let language = strings(df, "language")?;
+    let text_1 = strings(df, "text")?;
+    let text_2 = strings(df, "text_2")?;
+    let transliteration = strings(df, "transliteration")?;
+    let parsing_code = strings(df, "parsing_code")?;
+    let parsing = strings(df, "parsing")?;
+    let heading = strings(df, "heading")?;
+    let paragraph = strings(df, "paragraph")?;
+    let start_quote = strings(df, "start_quote")?;
+    let english = strings(df, "english_cell")?;
+    let puncutation = strings(df, "puncutation")?;
+    let end_quote = strings(df, "end_quote")?;
+    let footnotes = strings(df, "footnotes")?;
+    let end_text = strings(df, "end_text")?;
+    let crossref = df.column("crossref")?.list()?;
+
+    (0..df.height())
+        .map(|row| {
+            let error = |column: &'static str, reason: String| DatasetError::Conversion(ConversionError { bsb_sort: bsb_sort.get(row).unwrap_or_default(), column, reason });
+            let number = |column: &'static str, values: &UInt32Chunked| values.get(row).ok_or_else(|| error(column, "Missing value".to_string()));
+            let text = |values: &StringChunked| values.get(row).map(String::from);
+
+            let verse_id = number("Verse", verse_id)?;
+            if decode_verse_id(verse_id).is_none() {
+                Err(error("Verse", format!("Invalid verse id - '{verse_id}'")))?;
+            }
+            let language = text(&language).unwrap_or_default();
+            let crossref = crossref.get_as_series(row)
+                .map(|references| Ok::<_, PolarsError>(references.str()?.into_iter().flatten().map(String::from).collect_vec()))
+                .transpose()?;
+
+            Ok(InterlinearWordEntry {
+                hebrew_sort: number("Heb Sort", hebrew_sort)?,
+                greek_sort: number("Greek Sort", greek_sort)?,
+                bsb_sort: number("BSB Sort", bsb_sort)?,
+                verse_id,
+                language: language.parse::<Language>().map_err(|reason| error("Language", reason))?,
+                text_1: text(&text_1).unwrap_or_default(),
+                text_2: text(&text_2).unwrap_or_default(),
+                transliteration: text(&transliteration).unwrap_or_default(),
+                parsing_code: text(&parsing_code).unwrap_or_default(),
+                parsing: text(&parsing).unwrap_or_default(),
+                strongs_hebrew: strongs_hebrew.get(row),
+                strongs_greek: strongs_greek.get(row),
+                heading: text(&heading),
+                crossref,
+                paragraph: text(&paragraph),
+                start_quote: text(&start_quote),
+                english: text(&english)
+                    .ok_or_else(|| error(" BSB version ", "Translation is missing".to_string()))?
+                    .parse::<TranslatedWord>()
+                    .unwrap_or_else(|never| match never {}),
+                puncutation: text(&puncutation),
+                end_quote: text(&end_quote),
+                footnotes: text(&footnotes),
+                end_text: text(&end_text),
+            })
+        })
+        .collect()
+}
+
+/// Writes [`dataset_dataframe`] to a file
+pub fn write_dataset(path: impl AsRef<Path>, format: DatasetFormat, words: &[InterlinearWordEntry]) -> Result<(), DatasetError> {
+    let mut df = dataset_dataframe(words)?;
+    let file = File::create(path)?;
+    match format {
+        DatasetFormat::Parquet => { ParquetWriter::new(file).finish(&mut df)?; },
+        DatasetFormat::Ipc => IpcWriter::new(file).finish(&mut df)?,
+    }
+    Ok(())
+}
+
+/// Reads the words of a file written by [`write_dataset`], without the workbook
+pub fn read_dataset(path: impl AsRef<Path>, format: DatasetFormat) -> Result<Vec<InterlinearWordEntry>, DatasetError> {
+    let file = File::open(path)?;
+    let df = match format {
+        DatasetFormat::Parquet => ParquetReader::new(file).finish()?,
+        DatasetFormat::Ipc => IpcReader::new(file).finish()?,
+    };
+    words_from_dataframe(&df)
+}
+
+/// [`read_dataset`], grouped into verses with [`InterlinearVerseEntry::group`]
+pub fn read_dataset_verses(path: impl AsRef<Path>, format: DatasetFormat, order: WordOrder) -> Result<Vec<InterlinearVerseEntry>, DatasetError> {
+    Ok(InterlinearVerseEntry::group(read_dataset(path, format)?, order))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fixture::word_fixture;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() -> Result<(), DatasetError> {
+        let words = word_fixture();
+        for (format, extension) in [(DatasetFormat::Parquet, "parquet"), (DatasetFormat::Ipc, "arrow")] {
+            let path = std::env::temp_dir().join(format!("bsb_xlsx_dataset_{}.{extension}", std::process::id()));
+            assert_eq!(DatasetFormat::from_path(&path), Some(format));
+
+            write_dataset(&path, format, &words)?;
+            let read = read_dataset(&path, format);
+            let verses = read_dataset_verses(&path, format, WordOrder::Bsb);
+            std::fs::remove_file(&path)?;
+
+            assert_eq!(read?, words, "{format:?}");
+            assert_eq!(verses?, InterlinearVerseEntry::group(words.clone(), WordOrder::Bsb), "{format:?}");
+        }
+        assert_eq!(DatasetFormat::from_path("bsb_tables.xlsx"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn version() -> Result<(), DatasetError> {
+        let mut df = dataset_dataframe(&word_fixture())?;
+        df.with_column(Column::new("schema_version".into(), vec![SCHEMA_VERSION + 1; df.height()]))?;
+        assert!(matches!(words_from_dataframe(&df), Err(DatasetError::Version(Some(version))) if version == SCHEMA_VERSION + 1));
+
+        let df = df.drop("schema_version")?;
+        assert_eq!(words_from_dataframe(&df).unwrap_err().to_string(), "Dataset has no schema version");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_cells() -> Result<(), DatasetError> {
+        let words = word_fixture();
+        let mut df = dataset_dataframe(&words)?;
+        let mut language = vec![Some("Hebrew"); df.height()];
+        language[3] = Some("z");
+        df.with_column(Column::new("language".into(), language))?;
+
+        match words_from_dataframe(&df) {
+            Err(DatasetError::Conversion(error)) => assert_eq!(error, ConversionError { bsb_sort: words[3].bsb_sort, column: "Language", reason: "Invalid Language - 'z'".to_string() }),
+            other => panic!("Expected a conversion error, got {:?}", other),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use std::{mem::take, path::Path};
+
+use calamine::{Data, Range};
+
+use crate::{columns::ColumnMapping, interlinear::RawInterlinearEntry, xlsx::{raw_entries_from_range, ReadError}};
+
+/// How the cells of a row are separated
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Tab-separated, as published by the BSB, where quotes are part of the cell
+    Tab,
+    /// Comma-separated, where a cell may be quoted to contain commas, line breaks and `""` for a quote
+    Comma,
+}
+
+impl Delimiter {
+    /// `.tsv`, `.tab` and `.txt`, or `.csv`
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_lowercase();
+        Some(match extension.as_str() {
+            "tsv" | "tab" | "txt" => Self::Tab,
+            "csv" => Self::Comma,
+            _ => None?,
+        })
+    }
+
+    fn separator(self) -> char {
+        match self {
+            Self::Tab => '\t',
+            Self::Comma => ',',
+        }
+    }
+}
+
+/// Reads every row of a TSV or CSV file with the columns of `bsb_tables.xlsx`, see [`raw_entries_from_delimited`]
+pub fn read_delimited_entries(path: impl AsRef<Path>, delimiter: Delimiter, columns: &ColumnMapping) -> Result<Vec<RawInterlinearEntry>, ReadError> {
+    let text = std::fs::read_to_string(path)?;
+    raw_entries_from_delimited(&text, delimiter, columns)
+}
+
+/**
+Deserializes every row below the header row, exactly as the same cells in a worksheet
+- The rows are read into a [`Range`] of text cells and deserialized with [`raw_entries_from_range`]
+- Empty cells are [`Data::Empty`], and numbers are parsed from the text when a field needs one
+- Blank rows, like the trailing lines an editor leaves, are skipped
+*/
+pub fn raw_entries_from_delimited(text: &str, delimiter: Delimiter, columns: &ColumnMapping) -> Result<Vec<RawInterlinearEntry>, ReadError> {
+    let mut rows = split_rows(text.strip_prefix('\u{feff}').unwrap_or(text), delimiter);
+    rows.retain(|cells| cells.iter().any(|cell| !cell.is_empty()));
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut range = Range::new((0, 0), (rows.len().max(1) as u32 - 1, width.max(1) as u32 - 1));
+    for (row, cells) in rows.into_iter().enumerate() {
+        for (column, cell) in cells.into_iter().enumerate() {
+            if !cell.is_empty() {
+                range.set_value((row as u32, column as u32), Data::String(cell));
+            }
+        }
+    }
+    raw_entries_from_range(&mut range, columns)
+}
+
+/// Splits the text into rows of cells, where a row ends at `\n` or `\r\n`
+/// - An unclosed quote runs to the end of the text
+fn split_rows(text: &str, delimiter: Delimiter) -> Vec<Vec<String>> {
+    let quotes = delimiter == Delimiter::Comma;
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => match chars.next_if_eq(&'"') {
+                Some(quote) => cell.push(quote),
+                None => quoted = false,
+            },
+            '"' if quotes && cell.is_empty() => quoted = true,
+            c if quoted => cell.push(c),
+            c if c == delimiter.separator() => row.push(take(&mut cell)),
+            '\r' if chars.peek() == Some(&'\n') => {},
+            '\n' => {
+                row.push(take(&mut cell));
+                rows.push(take(&mut row));
+            },
+            c => cell.push(c),
+        }
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{conversion::{convert_entries_with, LanguageMode}, fixture::{raw_fixture, tsv_fixture_path}};
+
+    use super::*;
+
+    #[test]
+    fn tsv() -> Result<(), ReadError> {
+        let tsv = read_delimited_entries(tsv_fixture_path(), Delimiter::Tab, &ColumnMapping::default())?;
+        let xlsx = raw_fixture();
+        assert_eq!(tsv, xlsx);
+
+        let (tsv, tsv_errors, tsv_warnings) = convert_entries_with(tsv, LanguageMode::Strict);
+        let (xlsx, xlsx_errors, xlsx_warnings) = convert_entries_with(xlsx, LanguageMode::Strict);
+        assert_eq!(tsv.len(), 62);
+        assert_eq!(serde_json::to_vec(&tsv).unwrap(), serde_json::to_vec(&xlsx).unwrap());
+        assert_eq!((tsv_errors, tsv_warnings), (xlsx_errors, xlsx_warnings));
+        Ok(())
+    }
+
+    #[test]
+    fn csv() -> Result<(), ReadError> {
+        let tsv = std::fs::read_to_string(tsv_fixture_path()).unwrap();
+        let csv = tsv.lines()
+            .map(|line| line.split('\t').map(|cell| match cell.contains([',', '"']) {
+                true => format!("\"{}\"", cell.replace('"', "\"\"")),
+                false => cell.to_string(),
+            }).collect::<Vec<_>>().join(","))
+            .collect::<Vec<_>>()
+            .join("\r\n");
+        let columns = ColumnMapping::default();
+        assert_eq!(raw_entries_from_delimited(&csv, Delimiter::Comma, &columns)?, raw_entries_from_delimited(&tsv, Delimiter::Tab, &columns)?);
+        Ok(())
+    }
+
+    #[test]
+    fn blank_rows() -> Result<(), ReadError> {
+        let tsv = std::fs::read_to_string(tsv_fixture_path()).unwrap();
+        let columns = ColumnMapping::default();
+        let rows = raw_entries_from_delimited(&tsv, Delimiter::Tab, &columns)?;
+        let (header, body) = tsv.split_once('\n').unwrap();
+        for text in [format!("{tsv}\n\n"), format!("{tsv}\r\n\r\n"), format!("{header}\r\n\t\r\n{body}"), tsv.replace('\n', "\r\n")] {
+            assert_eq!(raw_entries_from_delimited(&text, Delimiter::Tab, &columns)?, rows);
+        }
+        assert_eq!(rows.len(), 62);
+        Ok(())
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(
+            split_rows("a,\"b, \"\"c\"\"\nd\",\r\n\"e", Delimiter::Comma),
+            [vec!["a", "b, \"c\"\nd", ""], vec!["e"]]
+        );
+        assert_eq!(split_rows("\"a\"\tb\n", Delimiter::Tab), [vec!["\"a\"", "b"]]);
+        assert_eq!(Delimiter::from_path("bsb_tables.TSV"), Some(Delimiter::Tab));
+        assert_eq!(Delimiter::from_path("bsb_tables.csv"), Some(Delimiter::Comma));
+        assert_eq!(Delimiter::from_path("bsb_tables.xlsx"), None);
+    }
+
+    #[test]
+    fn missing_column() {
+        match raw_entries_from_delimited("Heb Sort\tGreek Sort\tSort\n1\t0\t1\n", Delimiter::Tab, &ColumnMapping::default()) {
+            Err(ReadError::Columns(error)) => {
+                assert_eq!(error.missing[0], "BSB Sort");
+                assert_eq!(error.unexpected, ["Sort"]);
+            },
+            other => panic!("Expected a missing header, got {:?}", other),
+        }
+    }
+}
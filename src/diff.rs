@@ -0,0 +1,269 @@
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::{books::verse_id_reference, interlinear::RawInterlinearEntry, report::escape};
+
+/// The cells compared between matching words, as `(Excel column title, cell)`
+fn compared_cells(raw: &RawInterlinearEntry) -> [(&'static str, Option<&str>); 5] {
+    [
+        (" BSB version ", raw.english.as_deref()),
+        ("Parsing", raw.parsing_code.as_deref()),
+        ("footnotes", raw.footnotes.as_deref()),
+        ("Hdg", raw.heading.as_deref()),
+        ("Crossref", raw.crossref.as_deref()),
+    ]
+}
+
+/// The sort ids and Strong's number of a word
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WordKey {
+    pub hebrew_sort: u32,
+    pub greek_sort: u32,
+    pub bsb_sort: u32,
+    /// `"Str Heb"` or `"Str Grk"`, whichever the word has
+    pub strongs: Option<u32>,
+}
+
+impl WordKey {
+    fn new(raw: &RawInterlinearEntry) -> Self {
+        Self {
+            hebrew_sort: raw.hebrew_sort,
+            greek_sort: raw.greek_sort,
+            bsb_sort: raw.bsb_sort,
+            strongs: raw.strongs_hebrew.or(raw.strongs_greek),
+        }
+    }
+}
+
+/// A word only in one of the workbooks
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WordSummary {
+    #[serde(flatten)]
+    pub key: WordKey,
+    pub text: String,
+    /// The `" BSB version "` cell
+    pub english: Option<String>,
+}
+
+impl WordSummary {
+    fn new(raw: &RawInterlinearEntry) -> Self {
+        Self { key: WordKey::new(raw), text: raw.text_1.clone().unwrap_or_default(), english: raw.english.clone() }
+    }
+}
+
+/// A cell that is different in the new workbook
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    /// Excel column title of the cell
+    pub column: &'static str,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// A word in both workbooks, with the cells that changed
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ChangedWord {
+    pub old: WordKey,
+    pub new: WordKey,
+    /// The text of the word in the new workbook
+    pub text: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// The words of a verse that were added, removed or changed
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct VerseDiff {
+    pub verse_id: u32,
+    /// `"Genesis 1:1"`
+    pub reference: Option<String>,
+    pub added: Vec<WordSummary>,
+    pub removed: Vec<WordSummary>,
+    pub changed: Vec<ChangedWord>,
+}
+
+impl VerseDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/**
+The words that differ between two revisions of the workbook, by verse, in verse order
+
+Within a verse, a word of the old workbook matches the word of the new workbook
+- With the same `"Heb Sort"`, `"Greek Sort"` and Strong's number
+- Otherwise, the next one with the same Strong's number (or the same text when neither has one), since an added word renumbers every word after it
+
+Sort ids that changed aren't reported by themselves for the same reason, but both are in each [`ChangedWord`].
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct WorkbookDiff {
+    pub verses: Vec<VerseDiff>,
+}
+
+impl WorkbookDiff {
+    pub fn new(old: &[RawInterlinearEntry], new: &[RawInterlinearEntry]) -> Self {
+        let old_verses = old.iter().into_group_map_by(|raw| raw.verse_id);
+        let new_verses = new.iter().into_group_map_by(|raw| raw.verse_id);
+
+        let verses = old_verses.keys()
+            .chain(new_verses.keys())
+            .copied()
+            .sorted()
+            .dedup()
+            .map(|verse_id| diff_verse(verse_id, &original_order(old_verses.get(&verse_id)), &original_order(new_verses.get(&verse_id))))
+            .filter(|verse| !verse.is_empty())
+            .collect();
+        Self { verses }
+    }
+
+    /// `(added, removed, changed)` words in every verse
+    pub fn counts(&self) -> (usize, usize, usize) {
+        self.verses.iter().fold((0, 0, 0), |(added, removed, changed), verse| {
+            (added + verse.added.len(), removed + verse.removed.len(), changed + verse.changed.len())
+        })
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let (added, removed, changed) = self.counts();
+        let mut markdown = format!("# Workbook Diff\n\nVerses: {}\n\nAdded: {added}, Removed: {removed}, Changed: {changed}\n", self.verses.len());
+
+        for verse in self.verses.iter() {
+            let reference = verse.reference.clone().unwrap_or_else(|| format!("Verse {}", verse.verse_id));
+            markdown.push_str(&format!("\n## {reference}\n\n| Change | Heb Sort | Greek Sort | BSB Sort | Strong's | Word | Column | Old | New |\n| --- | --- | --- | --- | --- | --- | --- | --- | --- |\n"));
+
+            let mut row = |change: &str, key: &WordKey, text: &str, column: &str, old: Option<&str>, new: Option<&str>| {
+                let strongs = key.strongs.map(|strongs| strongs.to_string()).unwrap_or_default();
+                markdown.push_str(&format!(
+                    "| {change} | {} | {} | {} | {strongs} | {} | {} | {} | {} |\n",
+                    key.hebrew_sort, key.greek_sort, key.bsb_sort, escape(text), column.trim(), escape(old.unwrap_or_default()), escape(new.unwrap_or_default()),
+                ));
+            };
+            for word in verse.removed.iter() {
+                row("Removed", &word.key, &word.text, " BSB version ", word.english.as_deref(), None);
+            }
+            for word in verse.added.iter() {
+                row("Added", &word.key, &word.text, " BSB version ", None, word.english.as_deref());
+            }
+            for word in verse.changed.iter() {
+                for change in word.changes.iter() {
+                    row("Changed", &word.new, &word.text, change.column, change.old.as_deref(), change.new.as_deref());
+                }
+            }
+        }
+        markdown
+    }
+}
+
+/// The words of a verse by `"Heb Sort"` and `"Greek Sort"`
+fn original_order<'a>(words: Option<&Vec<&'a RawInterlinearEntry>>) -> Vec<&'a RawInterlinearEntry> {
+    words.into_iter().flatten().copied().sorted_by_key(|raw| (raw.hebrew_sort, raw.greek_sort)).collect()
+}
+
+fn diff_verse(verse_id: u32, old: &[&RawInterlinearEntry], new: &[&RawInterlinearEntry]) -> VerseDiff {
+    // the index in `new` of the word matching each word of `old`
+    let mut matches: Vec<Option<usize>> = vec![None; old.len()];
+    let mut matched = vec![false; new.len()];
+    let mut find = |same: &dyn Fn(&RawInterlinearEntry, &RawInterlinearEntry) -> bool| {
+        for (index, word) in old.iter().enumerate() {
+            if matches[index].is_some() {
+                continue;
+            }
+            if let Some(other) = (0..new.len()).find(|other| !matched[*other] && same(word, new[*other])) {
+                matches[index] = Some(other);
+                matched[other] = true;
+            }
+        }
+    };
+    find(&|old, new| WordKey { bsb_sort: 0, ..WordKey::new(old) } == WordKey { bsb_sort: 0, ..WordKey::new(new) });
+    find(&|old, new| {
+        let strongs = WordKey::new(old).strongs;
+        strongs == WordKey::new(new).strongs && (strongs.is_some() || old.text_1 == new.text_1)
+    });
+
+    let changed = old.iter()
+        .zip(&matches)
+        .filter_map(|(old, index)| Some((*old, new[(*index)?])))
+        .filter_map(|(old, new)| {
+            let changes = compared_cells(old).into_iter()
+                .zip(compared_cells(new))
+                .filter(|((_, old), (_, new))| old != new)
+                .map(|((column, old), (_, new))| FieldChange { column, old: old.map(String::from), new: new.map(String::from) })
+                .collect_vec();
+            (!changes.is_empty()).then(|| ChangedWord { old: WordKey::new(old), new: WordKey::new(new), text: new.text_1.clone().unwrap_or_default(), changes })
+        })
+        .collect();
+
+    VerseDiff {
+        verse_id,
+        reference: verse_id_reference(verse_id),
+        added: new.iter().zip(&matched).filter(|(_, matched)| !**matched).map(|(raw, _)| WordSummary::new(raw)).collect(),
+        removed: old.iter().zip(&matches).filter(|(_, index)| index.is_none()).map(|(raw, _)| WordSummary::new(raw)).collect(),
+        changed,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fixture::raw_fixture;
+
+    use super::*;
+
+    #[test]
+    fn same() {
+        assert_eq!(WorkbookDiff::new(&raw_fixture(), &raw_fixture()), WorkbookDiff::default());
+    }
+
+    #[test]
+    fn revised() {
+        let old = raw_fixture();
+        let mut new = old.clone();
+        new[2].english = Some(" the God ".to_string());
+        new[2].footnotes = Some("Hebrew Elohim".to_string());
+        let matthew = new.iter().position(|raw| raw.text_1.as_deref() == Some("Βίβλος")).unwrap();
+        new[matthew].parsing_code = Some("N-NMS".to_string());
+        new[matthew].crossref = Some("Luke 3:23".to_string());
+        // a word inserted after the first word of Matthew renumbers the rest of the verse
+        let verse_id = new[matthew].verse_id;
+        for raw in new.iter_mut().filter(|raw| raw.verse_id == verse_id && raw.greek_sort > 1) {
+            raw.greek_sort += 1;
+        }
+        let mut inserted = new[matthew].clone();
+        (inserted.greek_sort, inserted.bsb_sort, inserted.strongs_greek) = (2, 400000, Some(3588));
+        (inserted.text_1, inserted.english, inserted.crossref) = (Some("τῆς".to_string()), Some(" of the ".to_string()), None);
+        new.push(inserted);
+        let removed = new.remove(3);
+
+        let diff = WorkbookDiff::new(&old, &new);
+        assert_eq!(diff.counts(), (1, 1, 2));
+        assert_eq!(diff.verses.len(), 2);
+
+        let genesis = &diff.verses[0];
+        assert_eq!((genesis.verse_id, genesis.reference.as_deref()), (1, Some("Genesis 1:1")));
+        assert_eq!(genesis.removed, [WordSummary::new(&removed)]);
+        assert_eq!(genesis.changed[0].old, WordKey { hebrew_sort: 3, greek_sort: 0, bsb_sort: 2, strongs: Some(430) });
+        assert_eq!(genesis.changed[0].changes, [
+            FieldChange { column: " BSB version ", old: Some(" God ".to_string()), new: Some(" the God ".to_string()) },
+            FieldChange { column: "footnotes", old: None, new: Some("Hebrew Elohim".to_string()) },
+        ]);
+
+        let matthew = &diff.verses[1];
+        assert_eq!(matthew.reference.as_deref(), Some("Matthew 1:1"));
+        assert_eq!(matthew.added.iter().map(|word| (word.key.greek_sort, word.key.strongs)).collect_vec(), [(2, Some(3588))]);
+        assert_eq!(matthew.changed.len(), 1);
+        assert_eq!(matthew.changed[0].changes.iter().map(|change| change.column).collect_vec(), ["Parsing", "Crossref"]);
+
+        let markdown = diff.to_markdown();
+        assert!(markdown.starts_with("# Workbook Diff\n\nVerses: 2\n\nAdded: 1, Removed: 1, Changed: 2\n"));
+        assert!(markdown.contains("| Changed | 3 | 0 | 2 | 430 | אֱלֹהִ֑ים | BSB version |  God  |  the God  |\n"));
+        assert!(markdown.contains("| Added | 0 | 2 | 400000 | 3588 | τῆς | BSB version |  |  of the  |\n"));
+
+        let json: serde_json::Value = serde_json::from_str(&diff.to_json().unwrap()).unwrap();
+        assert_eq!(json["verses"][0]["removed"][0]["hebrew_sort"], removed.hebrew_sort);
+        assert_eq!(json["verses"][1]["changed"][0]["changes"][0], serde_json::json!({ "column": "Parsing", "old": "N-NFS", "new": "N-NMS" }));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{conversion::convert_entries, interlinear::{InterlinearWordEntry, RawInterlinearEntry}, xlsx::read_raw_entries};
+
+/// `tests/bsb_tables.xlsx`, a few verses of each testament
+pub(crate) fn fixture_path() -> String {
+    format!("{}/tests/bsb_tables.xlsx", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// `tests/bsb_tables.tsv`, the same rows as [`fixture_path`]
+pub(crate) fn tsv_fixture_path() -> String {
+    format!("{}/tests/bsb_tables.tsv", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Every row of [`fixture_path`]
+pub(crate) fn raw_fixture() -> Vec<RawInterlinearEntry> {
+    read_raw_entries(fixture_path()).unwrap()
+}
+
+/// Every row of [`fixture_path`], converted
+pub(crate) fn word_fixture() -> Vec<InterlinearWordEntry> {
+    convert_entries(raw_fixture()).0
+}
@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 /**
 N - Nominative
 V - Vocative
@@ -22,8 +24,12 @@ pub enum Case {
     Dative,
 }
 
+impl Case {
+    pub const ALL: [Self; 5] = [Self::Nominative, Self::Vocative, Self::Accusative, Self::Genitive, Self::Dative];
+}
+
 impl FromStr for Case {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
@@ -32,7 +38,7 @@ impl FromStr for Case {
             "a" => Self::Accusative,
             "g" => Self::Genitive,
             "d" => Self::Dative,
-            _ => Err(format!("Invalid Case - '{s}'"))?
+            _ => Err(GreekParseError::invalid(Component::Case, s))?
         })
     }
 }
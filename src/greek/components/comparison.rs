@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 /**
 C - Comparative
 S - Superlative
@@ -14,14 +16,18 @@ pub enum Comparison {
     Superlative,
 }
 
+impl Comparison {
+    pub const ALL: [Self; 2] = [Self::Comparative, Self::Superlative];
+}
+
 impl FromStr for Comparison {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
             "c" => Self::Comparative,
             "s" => Self::Superlative,
-            _ => Err(format!("Invalid Comparison - '{s}'"))?
+            _ => Err(GreekParseError::invalid(Component::Comparison, s))?
         })
     }
 }
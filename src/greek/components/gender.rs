@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 /**
 M - Masculine
 F - Feminine
@@ -16,15 +18,19 @@ pub enum Gender {
     Neuter,
 }
 
+impl Gender {
+    pub const ALL: [Self; 3] = [Self::Masculine, Self::Feminine, Self::Neuter];
+}
+
 impl FromStr for Gender {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
             "m" => Self::Masculine,
             "f" => Self::Feminine,
             "n" => Self::Neuter,
-            _ => Err(format!("Invalid Gender - '{s}'"))?
+            _ => Err(GreekParseError::invalid(Component::Gender, s))?
         })
     }
 }
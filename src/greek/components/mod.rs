@@ -12,3 +12,160 @@ pub trait ComponentCode {
     fn code(&self) -> &'static str;
     fn code_name(&self) -> &'static str;
 }
+
+/// Each kind of component in a parsing code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Component {
+    PartOfSpeech,
+    Tense,
+    Mood,
+    Voice,
+    Case,
+    Gender,
+    Person,
+    Number,
+    Comparison,
+}
+
+impl Component {
+    pub const ALL: [Self; 9] = [
+        Self::PartOfSpeech, Self::Tense, Self::Mood, Self::Voice, Self::Case, Self::Gender, Self::Person, Self::Number, Self::Comparison,
+    ];
+}
+
+impl std::fmt::Display for Component {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::PartOfSpeech => "Part of Speech",
+            Self::Tense => "Tense",
+            Self::Mood => "Mood",
+            Self::Voice => "Voice",
+            Self::Case => "Case",
+            Self::Gender => "Gender",
+            Self::Person => "Person",
+            Self::Number => "Number",
+            Self::Comparison => "Comparison",
+        })
+    }
+}
+
+/**
+Serializes a component as its [`ComponentCode::code_name`], like `"Aorist"`,
+and deserializes it from its name (ignoring case) or its code, like `"A"`
+
+Fields use [`as_code`] to serialize the code instead.
+*/
+macro_rules! serde_code_name {
+    ($($component:ty),* $(,)?) => {$(
+        impl serde::Serialize for $component {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str($crate::greek::components::ComponentCode::code_name(self))
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $component {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+                match Self::ALL.iter().find(|component| $crate::greek::components::ComponentCode::code_name(*component).eq_ignore_ascii_case(&value)) {
+                    Some(component) => Ok(*component),
+                    None => value.parse().map_err(serde::de::Error::custom),
+                }
+            }
+        }
+    )*};
+}
+
+pub(crate) use serde_code_name;
+
+serde_code_name!(case::Case, comparison::Comparison, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice);
+
+/**
+`#[serde(with = "as_code")]` serializes a component as its [`ComponentCode::code`], like `"A"`, instead of its name
+- `#[serde(with = "as_code::option")]` for an optional component
+- Either the code or the name is read back
+*/
+pub mod as_code {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::ComponentCode;
+
+    pub fn serialize<T: ComponentCode, S: Serializer>(component: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(component.code())
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::ComponentCode;
+
+        pub fn serialize<T: ComponentCode, S: Serializer>(component: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+            match component {
+                Some(component) => serializer.serialize_some(component.code()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+            Option::<T>::deserialize(deserializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use super::{*, tense::Tense, voice::Voice};
+
+    fn round_trip<T: ComponentCode + Serialize + for<'de> Deserialize<'de> + PartialEq + std::fmt::Debug>(all: &[T]) -> serde_json::Result<()> {
+        for component in all {
+            let json = serde_json::to_string(component)?;
+            assert_eq!(json, format!("\"{}\"", component.code_name()));
+            assert_eq!(&serde_json::from_str::<T>(&json)?, component);
+            assert_eq!(&serde_json::from_str::<T>(&format!("\"{}\"", component.code()))?, component);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn serde() -> serde_json::Result<()> {
+        round_trip(&case::Case::ALL)?;
+        round_trip(&comparison::Comparison::ALL)?;
+        round_trip(&gender::Gender::ALL)?;
+        round_trip(&mood::Mood::ALL)?;
+        round_trip(&number::Number::ALL)?;
+        round_trip(&part_of_speech::PartOfSpeech::ALL)?;
+        round_trip(&person::Person::ALL)?;
+        round_trip(&Tense::ALL)?;
+        round_trip(&Voice::ALL)?;
+
+        assert_eq!(serde_json::from_str::<Tense>("\"aorist\"")?, Tense::Aorist);
+        assert_eq!(serde_json::from_str::<Voice>("\"m/p\"")?, Voice::MiddlePassive);
+        assert_eq!(
+            serde_json::from_str::<Tense>("\"Aorst\"").unwrap_err().to_string(),
+            "Invalid Tense - 'Aorst' at segment 0, offset 0 of 'Aorst'"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn serde_as_code() -> serde_json::Result<()> {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Verb {
+            #[serde(with = "as_code")]
+            tense: Tense,
+            #[serde(with = "as_code::option")]
+            voice: Option<Voice>,
+        }
+
+        let verb = Verb { tense: Tense::SecondAorist, voice: Some(Voice::MiddlePassive) };
+        assert_eq!(serde_json::to_string(&verb)?, r#"{"tense":"2A","voice":"M/P"}"#);
+        assert_eq!(serde_json::from_str::<Verb>(r#"{"tense":"2A","voice":"M/P"}"#)?, verb);
+        assert_eq!(serde_json::from_str::<Verb>(r#"{"tense":"2nd Aorist","voice":null}"#)?, Verb { voice: None, ..verb });
+        Ok(())
+    }
+}
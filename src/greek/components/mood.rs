@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 /**
 I - Indicative
 M - Imperative
@@ -25,8 +27,12 @@ pub enum Mood {
     Participle,
 }
 
+impl Mood {
+    pub const ALL: [Self; 6] = [Self::Indicative, Self::Imperative, Self::Subjunctive, Self::Optative, Self::Infinitive, Self::Participle];
+}
+
 impl FromStr for Mood {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
@@ -36,7 +42,7 @@ impl FromStr for Mood {
             "o" => Self::Optative,
             "n" => Self::Infinitive,
             "p" => Self::Participle,
-            _ => Err(format!("Invalid Mood - '{s}'"))?
+            _ => Err(GreekParseError::invalid(Component::Mood, s))?
         })
     }
 }
@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 /**
 S - Singular
 P - Plural
@@ -13,14 +15,18 @@ pub enum Number {
     Plural,
 }
 
+impl Number {
+    pub const ALL: [Self; 2] = [Self::Singular, Self::Plural];
+}
+
 impl FromStr for Number {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
             "s" => Self::Singular,
             "p" => Self::Plural,
-            _ => Err(format!("Invalid Number - '{s}'"))?
+            _ => Err(GreekParseError::invalid(Component::Number, s))?
         })
     }
 }
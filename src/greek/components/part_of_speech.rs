@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 
 /**
 V - Verb
@@ -20,6 +22,8 @@ I - Interjection
 Prtcl - Particle
 Heb - Hebrew Word
 Aram - Aramaic Word
+Indec - Indeclinable
+IntPrtcl - Interrogative Particle
 */
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum PartOfSpeech {
@@ -57,10 +61,23 @@ pub enum PartOfSpeech {
     HebrewWord,
     /// Aram - Aramaic Word
     AramaicWord,
+    /// Indec - Indeclinable
+    Indeclinable,
+    /// IntPrtcl - Interrogative Particle
+    InterrogativeParticle,
+}
+
+impl PartOfSpeech {
+    pub const ALL: [Self; 19] = [
+        Self::Verb, Self::Noun, Self::Adverb, Self::Adjective, Self::Article, Self::DemonstrativePronoun,
+        Self::InterrogativeIndefinitePronoun, Self::PersonalPossessivePronoun, Self::ReciprocalPronoun,
+        Self::RelativePronoun, Self::ReflexivePronoun, Self::Preposition, Self::Conjunction, Self::Interjection,
+        Self::Particle, Self::HebrewWord, Self::AramaicWord, Self::Indeclinable, Self::InterrogativeParticle,
+    ];
 }
 
 impl FromStr for PartOfSpeech {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
@@ -81,7 +98,9 @@ impl FromStr for PartOfSpeech {
             "prtcl" => Self::Particle,
             "heb" => Self::HebrewWord,
             "aram" => Self::AramaicWord,
-            _ => Err(format!("Invalid Part of Speech - '{s}'"))?
+            "indec" => Self::Indeclinable,
+            "intprtcl" => Self::InterrogativeParticle,
+            _ => Err(GreekParseError::invalid(Component::PartOfSpeech, s))?
         })
     }
 }
@@ -112,6 +131,8 @@ impl ComponentCode for PartOfSpeech {
             Self::Particle => "Prtcl",
             Self::HebrewWord => "Heb",
             Self::AramaicWord => "Aram",
+            Self::Indeclinable => "Indec",
+            Self::InterrogativeParticle => "IntPrtcl",
         }
     }
 
@@ -134,6 +155,8 @@ impl ComponentCode for PartOfSpeech {
             Self::Particle => "Particle",
             Self::HebrewWord => "Hebrew Word",
             Self::AramaicWord => "Aramaic Word",
+            Self::Indeclinable => "Indeclinable",
+            Self::InterrogativeParticle => "Interrogative Particle",
         }
     }
 }
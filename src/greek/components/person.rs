@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 
 /**
 1 - 1st Person
@@ -17,15 +19,19 @@ pub enum Person {
     Third
 }
 
+impl Person {
+    pub const ALL: [Self; 3] = [Self::First, Self::Second, Self::Third];
+}
+
 impl FromStr for Person {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
             "1" => Self::First,
             "2" => Self::Second,
             "3" => Self::Third,
-            _ => Err(format!("Invalid Person - '{s}'"))?
+            _ => Err(GreekParseError::invalid(Component::Person, s))?
         })
     }
 
@@ -1,6 +1,8 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 /**
 P - Present
 I - Imperfect
@@ -8,6 +10,12 @@ F - Future
 A - Aorist
 R - Perfect
 L - Pluperfect
+2A - 2nd Aorist
+2F - 2nd Future
+2R - 2nd Perfect
+2L - 2nd Pluperfect
+
+The BSB codes never have a second tense, but other tag sets do, see [`Tense::base`]
 */
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Tense {
@@ -23,10 +31,40 @@ pub enum Tense {
     Perfect,
     /// L - Pluperfect
     Pluperfect,
+    /// 2A - 2nd Aorist
+    SecondAorist,
+    /// 2F - 2nd Future
+    SecondFuture,
+    /// 2R - 2nd Perfect
+    SecondPerfect,
+    /// 2L - 2nd Pluperfect
+    SecondPluperfect,
+}
+
+impl Tense {
+    pub const ALL: [Self; 10] = [
+        Self::Present, Self::Imperfect, Self::Future, Self::Aorist, Self::Perfect, Self::Pluperfect,
+        Self::SecondAorist, Self::SecondFuture, Self::SecondPerfect, Self::SecondPluperfect,
+    ];
+
+    /// The tense without the distinction of a second form, for comparing with tag sets that don't make it
+    pub fn base(&self) -> Self {
+        match self {
+            Self::SecondAorist => Self::Aorist,
+            Self::SecondFuture => Self::Future,
+            Self::SecondPerfect => Self::Perfect,
+            Self::SecondPluperfect => Self::Pluperfect,
+            tense => *tense,
+        }
+    }
+
+    pub fn is_second(&self) -> bool {
+        *self != self.base()
+    }
 }
 
 impl FromStr for Tense {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
@@ -36,7 +74,11 @@ impl FromStr for Tense {
             "a" => Self::Aorist,
             "r" => Self::Perfect,
             "l" => Self::Pluperfect,
-            _ => Err(format!("Invalid Tense - '{s}'"))?
+            "2a" => Self::SecondAorist,
+            "2f" => Self::SecondFuture,
+            "2r" => Self::SecondPerfect,
+            "2l" => Self::SecondPluperfect,
+            _ => Err(GreekParseError::invalid(Component::Tense, s))?
         })
     }
 }
@@ -56,6 +98,10 @@ impl ComponentCode for Tense {
             Self::Aorist => "A",
             Self::Perfect => "R",
             Self::Pluperfect => "L",
+            Self::SecondAorist => "2A",
+            Self::SecondFuture => "2F",
+            Self::SecondPerfect => "2R",
+            Self::SecondPluperfect => "2L",
         }
     }
 
@@ -67,6 +113,10 @@ impl ComponentCode for Tense {
             Self::Aorist => "Aorist",
             Self::Perfect => "Perfect",
             Self::Pluperfect => "Pluperfect",
+            Self::SecondAorist => "2nd Aorist",
+            Self::SecondFuture => "2nd Future",
+            Self::SecondPerfect => "2nd Perfect",
+            Self::SecondPluperfect => "2nd Pluperfect",
         }
     }
 }
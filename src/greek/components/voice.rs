@@ -1,11 +1,19 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::ComponentCode;
+use crate::greek::error::GreekParseError;
+
+use super::{Component, ComponentCode};
 /**
 A - Active
 M - Middle
 P - Passive
 M/P - Middle or Passive
+D - Middle Deponent
+O - Passive Deponent
+N - Middle or Passive Deponent
+X - No Voice Stated
+
+The BSB codes never have a deponent or an explicit lack of voice, but other tag sets do, see [`Voice::base`]
 */
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Voice {
@@ -17,10 +25,41 @@ pub enum Voice {
     Passive,
     /// M/P - Middle or Passive
     MiddlePassive,
+    /// D - Middle Deponent
+    MiddleDeponent,
+    /// O - Passive Deponent
+    PassiveDeponent,
+    /// N - Middle or Passive Deponent
+    MiddlePassiveDeponent,
+    /// X - No Voice Stated
+    NoVoice,
+}
+
+impl Voice {
+    pub const ALL: [Self; 8] = [
+        Self::Active, Self::Middle, Self::Passive, Self::MiddlePassive,
+        Self::MiddleDeponent, Self::PassiveDeponent, Self::MiddlePassiveDeponent, Self::NoVoice,
+    ];
+
+    /// The form of the voice without deponency, for comparing with tag sets that don't make the distinction
+    /// - [`Voice::NoVoice`] has no form, like a BSB code without a voice
+    pub fn base(&self) -> Option<Self> {
+        match self {
+            Self::MiddleDeponent => Some(Self::Middle),
+            Self::PassiveDeponent => Some(Self::Passive),
+            Self::MiddlePassiveDeponent => Some(Self::MiddlePassive),
+            Self::NoVoice => None,
+            voice => Some(*voice),
+        }
+    }
+
+    pub fn is_deponent(&self) -> bool {
+        matches!(self, Self::MiddleDeponent | Self::PassiveDeponent | Self::MiddlePassiveDeponent)
+    }
 }
 
 impl FromStr for Voice {
-    type Err = String;
+    type Err = GreekParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
@@ -28,7 +67,11 @@ impl FromStr for Voice {
             "m" => Self::Middle,
             "p" => Self::Passive,
             "m/p" => Self::MiddlePassive,
-            _ => Err(format!("Invalid Voice - '{s}'"))?
+            "d" => Self::MiddleDeponent,
+            "o" => Self::PassiveDeponent,
+            "n" => Self::MiddlePassiveDeponent,
+            "x" => Self::NoVoice,
+            _ => Err(GreekParseError::invalid(Component::Voice, s))?
         })
     }
 }
@@ -46,6 +89,10 @@ impl ComponentCode for Voice {
             Self::Middle => "M",
             Self::Passive => "P",
             Self::MiddlePassive => "M/P",
+            Self::MiddleDeponent => "D",
+            Self::PassiveDeponent => "O",
+            Self::MiddlePassiveDeponent => "N",
+            Self::NoVoice => "X",
         }
     }
 
@@ -55,6 +102,10 @@ impl ComponentCode for Voice {
             Self::Middle => "Middle",
             Self::Passive => "Passive",
             Self::MiddlePassive => "Middle or Passive",
+            Self::MiddleDeponent => "Middle Deponent",
+            Self::PassiveDeponent => "Passive Deponent",
+            Self::MiddlePassiveDeponent => "Middle or Passive Deponent",
+            Self::NoVoice => "No Voice Stated",
         }
     }
 }
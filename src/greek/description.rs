@@ -0,0 +1,204 @@
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::greek::{components::{Component, ComponentCode, case::Case, comparison::Comparison, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice}, error::GreekParseError, word::{Codes, GreekWordParsing}};
+
+/// `(name, component, code)` of every component, longest name first so that `Middle or Passive` is matched before `Middle`
+static NAMES: Lazy<Vec<(&'static str, Component, &'static str)>> = Lazy::new(|| {
+    fn names<T: ComponentCode>(all: &[T], component: Component) -> impl Iterator<Item = (&'static str, Component, &'static str)> {
+        all.iter().map(move |value| (value.code_name(), component, value.code()))
+    }
+    names(&Tense::ALL, Component::Tense)
+        .chain(names(&Mood::ALL, Component::Mood))
+        .chain(names(&Voice::ALL, Component::Voice))
+        .chain(names(&Case::ALL, Component::Case))
+        .chain(names(&Gender::ALL, Component::Gender))
+        .chain(names(&Person::ALL, Component::Person))
+        .chain(names(&Number::ALL, Component::Number))
+        .chain(names(&Comparison::ALL, Component::Comparison))
+        .sorted_by_key(|(name, _, _)| std::cmp::Reverse(name.len()))
+        .collect()
+});
+
+/**
+The order that components are listed in [`GreekWordParsing::describe_with`]
+
+Each group is joined with spaces, and the groups are joined with `" - "` after the part of speech.
+Empty groups are skipped, so one order works for every part of speech.
+
+- [`DescriptionOrder::BSB`]: `Verb - Aorist Participle Middle or Passive - Accusative Neuter Plural`
+- [`DescriptionOrder::BIBLE_HUB`]: `Verb - Aorist Participle Middle or Passive - Accusative Plural Neuter`
+- [`DescriptionOrder::ACCORDANCE`]: `Verb - Aorist Middle or Passive Participle - Accusative Neuter Plural`
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DescriptionOrder<'a> {
+    pub groups: &'a [&'a [Component]],
+}
+
+impl DescriptionOrder<'static> {
+    /// The order of the "Parsing" column in the workbook, which follows the order of the code
+    pub const BSB: Self = Self {
+        groups: &[
+            &[Component::Tense, Component::Mood, Component::Voice],
+            &[Component::Case, Component::Gender, Component::Person, Component::Number],
+            &[Component::Comparison],
+        ],
+    };
+
+    /// `Part of Speech  –  Person, Tense, Mood, Voice  –  Case, Number, Gender, Comparison`
+    pub const BIBLE_HUB: Self = Self {
+        groups: &[
+            &[Component::Person, Component::Tense, Component::Mood, Component::Voice],
+            &[Component::Case, Component::Number, Component::Gender, Component::Comparison],
+        ],
+    };
+
+    /// Tense, Voice, Mood, then Person, Case, Gender, Number
+    pub const ACCORDANCE: Self = Self {
+        groups: &[
+            &[Component::Tense, Component::Voice, Component::Mood],
+            &[Component::Person, Component::Case, Component::Gender, Component::Number],
+            &[Component::Comparison],
+        ],
+    };
+}
+
+impl Default for DescriptionOrder<'static> {
+    fn default() -> Self {
+        Self::BSB
+    }
+}
+
+impl Component {
+    /// The long-form name of this component in a parsing, if it has one
+    pub fn name(&self, parsing: &GreekWordParsing) -> Option<&'static str> {
+        match self {
+            Self::PartOfSpeech => Some(parsing.part_of_speech().code_name()),
+            Self::Tense => parsing.tense().map(|c| c.code_name()),
+            Self::Mood => parsing.mood().map(|c| c.code_name()),
+            Self::Voice => parsing.voice().map(|c| c.code_name()),
+            Self::Case => parsing.case().map(|c| c.code_name()),
+            Self::Gender => parsing.gender().map(|c| c.code_name()),
+            Self::Person => parsing.person().map(|c| c.code_name()),
+            Self::Number => parsing.number().map(|c| c.code_name()),
+            Self::Comparison => parsing.comparison().map(|c| c.code_name()),
+        }
+    }
+}
+
+pub(crate) fn describe(parsing: &GreekWordParsing, order: &DescriptionOrder) -> String {
+    let part_of_speech = parsing.part_of_speech().code_name();
+    let groups = order.groups.iter()
+        .map(|group| group.iter().filter_map(|component| component.name(parsing)).join(" "))
+        .filter(|group| !group.is_empty());
+    std::iter::once(part_of_speech.to_string()).chain(groups).join(" - ")
+}
+
+/// Reads a long-form description back into a parsing
+/// - Components may be listed in any order, so every [`DescriptionOrder`] can be read
+/// - Errors in the position of components are in the code assembled from the description, e.g. `Art-GF` for `Article - Genitive Feminine`
+pub(crate) fn parse_description(description: &str) -> Result<GreekWordParsing, GreekParseError> {
+    let mut groups = description.split(" - ");
+    let part_of_speech = groups.next().unwrap_or_default().trim();
+    let part_of_speech = PartOfSpeech::ALL.into_iter()
+        .find(|p| p.code_name() == part_of_speech)
+        .ok_or_else(|| GreekParseError::invalid(Component::PartOfSpeech, part_of_speech).at(description, 0, 0))?;
+
+    let mut codes: Codes = [None; 9];
+    for (segment, group) in groups.enumerate().map(|(index, group)| (index + 1, group)) {
+        let mut rest = group.trim_start();
+        while !rest.is_empty() {
+            let offset = group[..group.len() - rest.len()].chars().count();
+            let (name, component, code) = NAMES.iter()
+                .find(|(name, _, _)| rest.starts_with(name) && rest[name.len()..].chars().next().is_none_or(|c| c == ' '))
+                .ok_or_else(|| GreekParseError::Unknown { input: description.to_string(), segment, offset, found: rest.trim_end().to_string() })?;
+            if codes[*component as usize].replace(code).is_some() {
+                Err(GreekParseError::Unexpected { input: description.to_string(), segment, offset, found: *component })?;
+            }
+            rest = rest[name.len()..].trim_start();
+        }
+    }
+
+    codes[Component::PartOfSpeech as usize] = Some(part_of_speech.code());
+    GreekWordParsing::from_codes(codes)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fixture::raw_fixture;
+
+    use super::*;
+
+    #[test]
+    fn orders() -> Result<(), GreekParseError> {
+        let parsing = GreekWordParsing::parse("V-APM/P-ANP")?;
+        assert_eq!(parsing.describe(), "Verb - Aorist Participle Middle or Passive - Accusative Neuter Plural");
+        assert_eq!(parsing.describe_with(&DescriptionOrder::BIBLE_HUB), "Verb - Aorist Participle Middle or Passive - Accusative Plural Neuter");
+        assert_eq!(parsing.describe_with(&DescriptionOrder::ACCORDANCE), "Verb - Aorist Middle or Passive Participle - Accusative Neuter Plural");
+
+        let parsing = GreekWordParsing::parse("V-AIA-3S")?;
+        assert_eq!(parsing.describe(), "Verb - Aorist Indicative Active - 3rd Person Singular");
+        assert_eq!(parsing.describe_with(&DescriptionOrder::BIBLE_HUB), "Verb - 3rd Person Aorist Indicative Active - Singular");
+
+        let custom = DescriptionOrder { groups: &[&[Component::Number, Component::Case]] };
+        assert_eq!(GreekWordParsing::parse("Art-AMS")?.describe_with(&custom), "Article - Singular Accusative");
+        Ok(())
+    }
+
+    #[test]
+    fn bsb() -> Result<(), GreekParseError> {
+        let cases = [
+            ("N-NFS", "Noun - Nominative Feminine Singular"),
+            ("PPro-GM3S", "Personal / Possessive Pronoun - Genitive Masculine 3rd Person Singular"),
+            ("PPro-G1P", "Personal / Possessive Pronoun - Genitive 1st Person Plural"),
+            ("V-M-2P", "Verb - Imperative - 2nd Person Plural"),
+            ("V-PNA", "Verb - Present Infinitive Active"),
+            ("Adj-NFS-S", "Adjective - Nominative Feminine Singular - Superlative"),
+            ("Adv-C", "Adverb - Comparative"),
+            ("Adj", "Adjective"),
+            ("Conj", "Conjunction"),
+            ("Heb", "Hebrew Word"),
+            ("Indec", "Indeclinable"),
+            ("IntPrtcl", "Interrogative Particle"),
+        ];
+        for (code, description) in cases {
+            assert_eq!(GreekWordParsing::parse(code)?.describe(), description);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_description() -> Result<(), GreekParseError> {
+        for order in [DescriptionOrder::BSB, DescriptionOrder::BIBLE_HUB, DescriptionOrder::ACCORDANCE] {
+            for code in ["V-APM/P-ANP", "V-AIM-3S", "V-M-2P", "V-PNA", "PPro-G1P", "PPro-GM3S", "Adj-NFS-S", "Adj", "Adv-C", "N", "Art-VFP", "Indec", "IntPrtcl", "Heb"] {
+                let parsing = GreekWordParsing::parse(code)?;
+                assert_eq!(GreekWordParsing::from_description(&parsing.describe_with(&order))?, parsing);
+            }
+        }
+        assert_eq!(
+            GreekWordParsing::from_description("Article - Genitive Feminine"),
+            Err(GreekParseError::Missing { input: "Art-GF".to_string(), segment: 1, offset: 2, expected: Component::Number })
+        );
+        assert_eq!(
+            GreekWordParsing::from_description("Noun - Genitive Feminine Singular Singular"),
+            Err(GreekParseError::Unexpected { input: "Noun - Genitive Feminine Singular Singular".to_string(), segment: 1, offset: 27, found: Component::Number })
+        );
+        assert_eq!(
+            GreekWordParsing::from_description("Noun - Genitiv Feminine Singular").map_err(|error| error.to_string()),
+            Err("Unknown component - 'Genitiv Feminine Singular' at segment 1, offset 0 of 'Noun - Genitiv Feminine Singular'".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fixture() -> Result<(), GreekParseError> {
+        let entries = raw_fixture();
+        let greek = entries.iter().filter(|entry| entry.language == "Greek").collect::<Vec<_>>();
+        assert!(!greek.is_empty());
+        for entry in greek {
+            let code = entry.parsing_code.as_deref().unwrap();
+            assert_eq!(Some(GreekWordParsing::parse(code)?.describe().as_str()), entry.parsing.as_deref(), "{code}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use std::{error::Error, fmt::Display};
+
+use crate::greek::components::Component;
+
+/**
+Why a Greek parsing code could not be read
+
+`segment` is the index of the `-` separated segment, and `offset` is the character offset within it.
+- `N-XYZ`: [`GreekParseError::Invalid`] Case `X` at segment 1, offset 0
+- `Art-AF`: [`GreekParseError::Missing`] Number at segment 1, offset 2
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GreekParseError {
+    /// `found` is not a valid code for the expected component
+    Invalid {
+        input: String,
+        segment: usize,
+        offset: usize,
+        expected: Component,
+        found: String,
+    },
+    /// The code ended before a required component
+    Missing {
+        input: String,
+        segment: usize,
+        offset: usize,
+        expected: Component,
+    },
+    /// A component that is not allowed, such as the person of an infinitive in [`ParsingMode::Strict`](crate::greek::word::ParsingMode::Strict)
+    Unexpected {
+        input: String,
+        segment: usize,
+        offset: usize,
+        found: Component,
+    },
+    /// Text that is not any component, in a description read by [`GreekWordParsing::from_description`](crate::greek::word::GreekWordParsing::from_description),
+    /// or left over after the last component of a code, like the `X` of `N-NFSX`
+    Unknown {
+        input: String,
+        segment: usize,
+        offset: usize,
+        found: String,
+    },
+}
+
+impl GreekParseError {
+    /// The error of a component parser, which only sees its own code
+    pub(crate) fn invalid(expected: Component, found: &str) -> Self {
+        Self::Invalid { input: found.to_string(), segment: 0, offset: 0, expected, found: found.to_string() }
+    }
+
+    /// Places the error in the code it was found in
+    pub(crate) fn at(self, code: &str, at_segment: usize, at_offset: usize) -> Self {
+        match self {
+            Self::Invalid { expected, found, .. } => Self::Invalid { input: code.to_string(), segment: at_segment, offset: at_offset, expected, found },
+            Self::Missing { expected, .. } => Self::Missing { input: code.to_string(), segment: at_segment, offset: at_offset, expected },
+            Self::Unexpected { found, .. } => Self::Unexpected { input: code.to_string(), segment: at_segment, offset: at_offset, found },
+            Self::Unknown { found, .. } => Self::Unknown { input: code.to_string(), segment: at_segment, offset: at_offset, found },
+        }
+    }
+
+    /// The whole code that was being read
+    pub fn input(&self) -> &str {
+        match self {
+            Self::Invalid { input, .. } | Self::Missing { input, .. } | Self::Unexpected { input, .. } | Self::Unknown { input, .. } => input,
+        }
+    }
+
+    pub fn segment(&self) -> usize {
+        match self {
+            Self::Invalid { segment, .. } | Self::Missing { segment, .. } | Self::Unexpected { segment, .. } | Self::Unknown { segment, .. } => *segment,
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::Invalid { offset, .. } | Self::Missing { offset, .. } | Self::Unexpected { offset, .. } | Self::Unknown { offset, .. } => *offset,
+        }
+    }
+}
+
+impl Display for GreekParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid { expected, found, .. } => write!(f, "Invalid {expected} - '{found}'")?,
+            Self::Missing { expected, .. } => write!(f, "{expected} is required")?,
+            Self::Unexpected { found, .. } => write!(f, "{found} is not allowed")?,
+            Self::Unknown { found, .. } => write!(f, "Unknown component - '{found}'")?,
+        };
+        write!(f, " at segment {}, offset {} of '{}'", self.segment(), self.offset(), self.input())
+    }
+}
+
+impl Error for GreekParseError {}
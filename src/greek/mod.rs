@@ -1,3 +1,8 @@
 pub mod components;
+pub mod description;
+pub mod error;
 pub mod parsings;
+pub mod query;
+pub mod reader;
+pub mod tag_sets;
 pub mod word;
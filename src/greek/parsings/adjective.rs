@@ -1,6 +1,6 @@
 use std::ops::{Deref, DerefMut};
 
-use crate::greek::{components::{case::Case, comparison::Comparison, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, case::Case, comparison::Comparison, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct AdjectiveData {
@@ -26,9 +26,9 @@ impl DerefMut for AdjectiveParsing {
     }
 }
 
-impl Into<GreekWordParsing> for AdjectiveParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::Adjective(self)
+impl From<AdjectiveParsing> for GreekWordParsing {
+    fn from(value: AdjectiveParsing) -> Self {
+        GreekWordParsing::Adjective(value)
     }
 }
 
@@ -40,16 +40,18 @@ impl PartOfSpeechParsing for AdjectiveParsing {
     /// - `Adj`: No components
     /// - `Adj-AFP`: Case, Gender, Number
     /// - `Adj-AFP-C`: Case, Gender, Number, Comparison
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        let Some(segment) = segments.next() else { return Ok(Self(None)) };
-        let mut chars = segment
-            .split_inclusive(|_| true);
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        if !reader.next_segment() { return Ok(Self(None)) };
 
-        let case: Case = chars.next().ok_or_else(|| format!("Adjective: Case is required"))?.parse()?;
-        let gender: Gender = chars.next().ok_or_else(|| format!("Adjective: Gender is required"))?.parse()?;
-        let number: Number = chars.next().ok_or_else(|| format!("Adjective: Number is required"))?.parse()?;
+        let case: Case = reader.required(Component::Case)?;
+        let gender: Gender = reader.required(Component::Gender)?;
+        let number: Number = reader.required(Component::Number)?;
+        reader.end_segment()?;
 
-        let comparison: Option<Comparison> = segments.next().map(|c| c.parse()).transpose()?;
+        let comparison: Option<Comparison> = match reader.next_segment() {
+            true => Some(reader.required_rest(Component::Comparison)?),
+            false => None,
+        };
 
         Ok(Self(Some(AdjectiveData {
             gender,
@@ -59,6 +61,14 @@ impl PartOfSpeechParsing for AdjectiveParsing {
         })))
     }
 
+    fn to_code(&self) -> String {
+        match self.0 {
+            Some(AdjectiveData { gender, number, case, comparison: Some(comparison) }) => format!("{}-{case}{gender}{number}-{comparison}", Self::part_of_speech()),
+            Some(AdjectiveData { gender, number, case, comparison: None }) => format!("{}-{case}{gender}{number}", Self::part_of_speech()),
+            None => Self::part_of_speech().to_string(),
+        }
+    }
+
     fn case(&self) -> Option<Case> { self.map(|s| s.case) }
     fn comparison(&self) -> Option<Comparison> { self.and_then(|s| s.comparison) }
     fn gender(&self) -> Option<Gender> { self.map(|s| s.gender) }
@@ -70,7 +80,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("Adj")?,
             GreekWordParsing::Adjective(AdjectiveParsing(None))
@@ -1,15 +1,13 @@
-use std::ops::{Deref, DerefMut};
-
-use crate::greek::{components::{case::Case, comparison::Comparison, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, comparison::Comparison, part_of_speech::PartOfSpeech}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct AdverbParsing {
     comparison: Option<Comparison>
 }
 
-impl Into<GreekWordParsing> for AdverbParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::Adverb(self)
+impl From<AdverbParsing> for GreekWordParsing {
+    fn from(value: AdverbParsing) -> Self {
+        GreekWordParsing::Adverb(value)
     }
 }
 
@@ -20,18 +18,26 @@ impl PartOfSpeechParsing for AdverbParsing {
 
     /// - `Adv`: No components
     /// - `Adv-C`: Comparison
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        let Some(segment) = segments.next() else { return Ok(Self { comparison: None }) };
-        let mut chars = segment
-            .split_inclusive(|_| true);
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        if !reader.next_segment() { return Ok(Self { comparison: None }) };
 
-        let comparison: Option<Comparison> = chars.next().map(|c| c.parse()).transpose()?;
+        let comparison: Option<Comparison> = match reader.is_empty() {
+            true => None,
+            false => Some(reader.required(Component::Comparison)?),
+        };
 
         Ok(Self {
             comparison,
         })
     }
 
+    fn to_code(&self) -> String {
+        match self.comparison {
+            Some(comparison) => format!("{}-{comparison}", Self::part_of_speech()),
+            None => Self::part_of_speech().to_string(),
+        }
+    }
+
     fn comparison(&self) -> Option<Comparison> { self.comparison }
 }
 
@@ -40,7 +46,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("Adv")?,
             GreekWordParsing::Adverb(AdverbParsing { comparison: None })
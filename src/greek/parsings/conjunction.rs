@@ -1,11 +1,11 @@
-use crate::greek::{components::part_of_speech::PartOfSpeech, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::part_of_speech::PartOfSpeech, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ConjunctionParsing;
 
-impl Into<GreekWordParsing> for ConjunctionParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::Conjunction(self)
+impl From<ConjunctionParsing> for GreekWordParsing {
+    fn from(value: ConjunctionParsing) -> Self {
+        GreekWordParsing::Conjunction(value)
     }
 }
 
@@ -15,7 +15,7 @@ impl PartOfSpeechParsing for ConjunctionParsing {
     }
 
     /// - `Conj`: No components
-    fn parse_segments(_segments: std::str::Split<'_, &str>) -> Result<Self, String> {
+    fn parse_segments(_reader: &mut CodeReader) -> Result<Self, GreekParseError> {
         Ok(Self)
     }
 }
@@ -25,7 +25,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("Conj")?,
             GreekWordParsing::Conjunction(ConjunctionParsing)
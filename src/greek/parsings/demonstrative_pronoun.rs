@@ -1,4 +1,4 @@
-use crate::greek::{components::{case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DemonstrativePronounParsing {
@@ -7,9 +7,9 @@ pub struct DemonstrativePronounParsing {
     case: Case,
 }
 
-impl Into<GreekWordParsing> for DemonstrativePronounParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::DemonstrativePronoun(self)
+impl From<DemonstrativePronounParsing> for GreekWordParsing {
+    fn from(value: DemonstrativePronounParsing) -> Self {
+        GreekWordParsing::DemonstrativePronoun(value)
     }
 }
 
@@ -19,13 +19,11 @@ impl PartOfSpeechParsing for DemonstrativePronounParsing {
     }
 
     /// - `DPro-AFP`: Case, Gender, Number
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        let mut chars = segments.next()
-            .ok_or_else(|| format!("Case, Number, Gender not included"))?
-            .split_inclusive(|_| true);
-        let case: Case = chars.next().ok_or_else(|| format!("DemonstrativePronoun: Case is required"))?.parse()?;
-        let gender: Gender = chars.next().ok_or_else(|| format!("DemonstrativePronoun: Gender is required"))?.parse()?;
-        let number: Number = chars.next().ok_or_else(|| format!("DemonstrativePronoun: Number is required"))?.parse()?;
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        reader.next_segment();
+        let case: Case = reader.required(Component::Case)?;
+        let gender: Gender = reader.required(Component::Gender)?;
+        let number: Number = reader.required(Component::Number)?;
 
         Ok(Self {
             gender,
@@ -34,6 +32,10 @@ impl PartOfSpeechParsing for DemonstrativePronounParsing {
         })
     }
 
+    fn to_code(&self) -> String {
+        format!("{}-{}{}{}", Self::part_of_speech(), self.case, self.gender, self.number)
+    }
+
     fn case(&self) -> Option<Case> { Some(self.case) }
     fn gender(&self) -> Option<Gender> { Some(self.gender) }
     fn number(&self) -> Option<Number> { Some(self.number) }
@@ -44,7 +46,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("DPro-AFP")?,
             GreekWordParsing::DemonstrativePronoun(DemonstrativePronounParsing {
@@ -1,11 +1,11 @@
-use crate::greek::{components::part_of_speech::PartOfSpeech, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::part_of_speech::PartOfSpeech, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct HebrewWordParsing;
 
-impl Into<GreekWordParsing> for HebrewWordParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::HebrewWord(self)
+impl From<HebrewWordParsing> for GreekWordParsing {
+    fn from(value: HebrewWordParsing) -> Self {
+        GreekWordParsing::HebrewWord(value)
     }
 }
 
@@ -15,7 +15,7 @@ impl PartOfSpeechParsing for HebrewWordParsing {
     }
 
     /// - `Heb`: No components
-    fn parse_segments(_segments: std::str::Split<'_, &str>) -> Result<Self, String> {
+    fn parse_segments(_reader: &mut CodeReader) -> Result<Self, GreekParseError> {
         Ok(Self)
     }
 }
@@ -25,7 +25,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("Heb")?,
             GreekWordParsing::HebrewWord(HebrewWordParsing)
@@ -0,0 +1,36 @@
+use crate::greek::{components::part_of_speech::PartOfSpeech, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IndeclinableParsing;
+
+impl From<IndeclinableParsing> for GreekWordParsing {
+    fn from(value: IndeclinableParsing) -> Self {
+        GreekWordParsing::Indeclinable(value)
+    }
+}
+
+impl PartOfSpeechParsing for IndeclinableParsing {
+    fn part_of_speech() -> PartOfSpeech {
+        PartOfSpeech::Indeclinable
+    }
+
+    /// - `Indec`: No components
+    fn parse_segments(_reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bsb() -> Result<(), GreekParseError> {
+        assert_eq!(
+            GreekWordParsing::parse("Indec")?,
+            GreekWordParsing::Indeclinable(IndeclinableParsing)
+        );
+
+        Ok(())
+    }
+}
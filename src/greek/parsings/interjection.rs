@@ -1,11 +1,11 @@
-use crate::greek::{components::part_of_speech::PartOfSpeech, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::part_of_speech::PartOfSpeech, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct InterjectionParsing;
 
-impl Into<GreekWordParsing> for InterjectionParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::Interjection(self)
+impl From<InterjectionParsing> for GreekWordParsing {
+    fn from(value: InterjectionParsing) -> Self {
+        GreekWordParsing::Interjection(value)
     }
 }
 
@@ -15,7 +15,7 @@ impl PartOfSpeechParsing for InterjectionParsing {
     }
 
     /// - `I`: No components
-    fn parse_segments(_segments: std::str::Split<'_, &str>) -> Result<Self, String> {
+    fn parse_segments(_reader: &mut CodeReader) -> Result<Self, GreekParseError> {
         Ok(Self)
     }
 }
@@ -25,7 +25,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("I")?,
             GreekWordParsing::Interjection(InterjectionParsing)
@@ -0,0 +1,36 @@
+use crate::greek::{components::part_of_speech::PartOfSpeech, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InterrogativeParticleParsing;
+
+impl From<InterrogativeParticleParsing> for GreekWordParsing {
+    fn from(value: InterrogativeParticleParsing) -> Self {
+        GreekWordParsing::InterrogativeParticle(value)
+    }
+}
+
+impl PartOfSpeechParsing for InterrogativeParticleParsing {
+    fn part_of_speech() -> PartOfSpeech {
+        PartOfSpeech::InterrogativeParticle
+    }
+
+    /// - `IntPrtcl`: No components
+    fn parse_segments(_reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        Ok(Self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bsb() -> Result<(), GreekParseError> {
+        assert_eq!(
+            GreekWordParsing::parse("IntPrtcl")?,
+            GreekWordParsing::InterrogativeParticle(InterrogativeParticleParsing)
+        );
+
+        Ok(())
+    }
+}
@@ -5,8 +5,10 @@ pub mod article;
 pub mod conjunction;
 pub mod demonstrative_pronoun;
 pub mod hebrew_word;
+pub mod indeclinable;
 pub mod interjection;
 pub mod interrogative_indefinite_pronoun;
+pub mod interrogative_particle;
 pub mod noun;
 pub mod particle;
 pub mod personal_possessive_pronoun;
@@ -1,6 +1,6 @@
 use std::ops::{Deref, DerefMut};
 
-use crate::greek::{components::{case::Case, comparison::Comparison, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct NounData {
@@ -25,9 +25,9 @@ impl DerefMut for NounParsing {
     }
 }
 
-impl Into<GreekWordParsing> for NounParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::Noun(self)
+impl From<NounParsing> for GreekWordParsing {
+    fn from(value: NounParsing) -> Self {
+        GreekWordParsing::Noun(value)
     }
 }
 
@@ -38,14 +38,12 @@ impl PartOfSpeechParsing for NounParsing {
 
     /// - `N`: No components
     /// - `N-AFP`: Case, Gender, Number
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        let Some(segment) = segments.next() else { return Ok(Self(None)) };
-        let mut chars = segment
-            .split_inclusive(|_| true);
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        if !reader.next_segment() { return Ok(Self(None)) };
 
-        let case: Case = chars.next().ok_or_else(|| format!("Noun: Case is required"))?.parse()?;
-        let gender: Gender = chars.next().ok_or_else(|| format!("Noun: Gender is required"))?.parse()?;
-        let number: Number = chars.next().ok_or_else(|| format!("Noun: Number is required"))?.parse()?;
+        let case: Case = reader.required(Component::Case)?;
+        let gender: Gender = reader.required(Component::Gender)?;
+        let number: Number = reader.required(Component::Number)?;
 
         Ok(Self(Some(NounData {
             gender,
@@ -54,6 +52,13 @@ impl PartOfSpeechParsing for NounParsing {
         })))
     }
 
+    fn to_code(&self) -> String {
+        match self.0 {
+            Some(NounData { gender, number, case }) => format!("{}-{case}{gender}{number}", Self::part_of_speech()),
+            None => Self::part_of_speech().to_string(),
+        }
+    }
+
     fn case(&self) -> Option<Case> { self.map(|s| s.case) }
     fn gender(&self) -> Option<Gender> { self.map(|s| s.gender) }
     fn number(&self) -> Option<Number> { self.map(|s| s.number) }
@@ -64,7 +69,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("N")?,
             GreekWordParsing::Noun(NounParsing(None))
@@ -1,4 +1,4 @@
-use crate::greek::{components::{case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech, person::Person}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, ComponentCode, case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech, person::Person}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PersonalPossessivePronounParsing {
@@ -8,9 +8,9 @@ pub struct PersonalPossessivePronounParsing {
     number: Number,
 }
 
-impl Into<GreekWordParsing> for PersonalPossessivePronounParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::PersonalPossessivePronoun(self)
+impl From<PersonalPossessivePronounParsing> for GreekWordParsing {
+    fn from(value: PersonalPossessivePronounParsing) -> Self {
+        GreekWordParsing::PersonalPossessivePronoun(value)
     }
 }
 
@@ -21,19 +21,13 @@ impl PartOfSpeechParsing for PersonalPossessivePronounParsing {
 
     /// - `PPro-A1P`: Case, Person, Number
     /// - `PPro-AF1P`: Case, Gender, Person, Number
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        let mut chars = segments.next()
-            .ok_or_else(|| format!("Case, Person, Number not included"))?
-            .split_inclusive(|_| true).peekable();
-
-        let case: Case = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Case is required"))?.parse()?;
-        let gender: Option<Gender> = chars.peek().ok_or_else(|| format!("Personal/Possessive Pronoun: Number is required"))?.parse().ok();
-        // only consume if the gender successfully parses
-        if gender.is_some() { chars.next(); }
-
-        let person: Option<Person> = chars.peek().ok_or_else(|| format!("Personal/Possessive Pronoun: Number is required"))?.parse().ok();
-        if person.is_some() { chars.next(); }
-        let number: Number = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Number is required"))?.parse()?;
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        reader.next_segment();
+        let case: Case = reader.required(Component::Case)?;
+        // only consumed if they successfully parse
+        let gender: Option<Gender> = reader.optional(Component::Number)?;
+        let person: Option<Person> = reader.optional(Component::Number)?;
+        let number: Number = reader.required(Component::Number)?;
 
         Ok(Self {
             case,
@@ -43,6 +37,12 @@ impl PartOfSpeechParsing for PersonalPossessivePronounParsing {
         })
     }
 
+    fn to_code(&self) -> String {
+        let gender = self.gender.map(|g| g.code()).unwrap_or_default();
+        let person = self.person.map(|p| p.code()).unwrap_or_default();
+        format!("{}-{}{gender}{person}{}", Self::part_of_speech(), self.case, self.number)
+    }
+
     fn case(&self) -> Option<Case> { Some(self.case) }
     fn gender(&self) -> Option<Gender> { self.gender }
     fn person(&self) -> Option<Person> { self.person }
@@ -54,7 +54,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("PPro-A1P")?,
             GreekWordParsing::PersonalPossessivePronoun(PersonalPossessivePronounParsing {
@@ -1,11 +1,11 @@
-use crate::greek::{components::part_of_speech::PartOfSpeech, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::part_of_speech::PartOfSpeech, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PrepositionParsing;
 
-impl Into<GreekWordParsing> for PrepositionParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::Preposition(self)
+impl From<PrepositionParsing> for GreekWordParsing {
+    fn from(value: PrepositionParsing) -> Self {
+        GreekWordParsing::Preposition(value)
     }
 }
 
@@ -15,7 +15,7 @@ impl PartOfSpeechParsing for PrepositionParsing {
     }
 
     /// - `Prep`: No components
-    fn parse_segments(_segments: std::str::Split<'_, &str>) -> Result<Self, String> {
+    fn parse_segments(_reader: &mut CodeReader) -> Result<Self, GreekParseError> {
         Ok(Self)
     }
 }
@@ -25,7 +25,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("Prep")?,
             GreekWordParsing::Preposition(PrepositionParsing)
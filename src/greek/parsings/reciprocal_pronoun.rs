@@ -1,4 +1,4 @@
-use crate::greek::{components::{case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ReciprocalPronounParsing {
@@ -7,9 +7,9 @@ pub struct ReciprocalPronounParsing {
     case: Case,
 }
 
-impl Into<GreekWordParsing> for ReciprocalPronounParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::ReciprocalPronoun(self)
+impl From<ReciprocalPronounParsing> for GreekWordParsing {
+    fn from(value: ReciprocalPronounParsing) -> Self {
+        GreekWordParsing::ReciprocalPronoun(value)
     }
 }
 
@@ -19,13 +19,11 @@ impl PartOfSpeechParsing for ReciprocalPronounParsing {
     }
 
     /// - `RecPro-AMP`: Case, Gender, Number
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        let mut chars = segments.next()
-            .ok_or_else(|| format!("Case, Gender, Number not included"))?
-            .split_inclusive(|_| true);
-        let case: Case = chars.next().ok_or_else(|| format!("ReciprocalPronoun: Case is required"))?.parse()?;
-        let gender: Gender = chars.next().ok_or_else(|| format!("ReciprocalPronoun: Gender is required"))?.parse()?;
-        let number: Number = chars.next().ok_or_else(|| format!("ReciprocalPronoun: Number is required"))?.parse()?;
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        reader.next_segment();
+        let case: Case = reader.required(Component::Case)?;
+        let gender: Gender = reader.required(Component::Gender)?;
+        let number: Number = reader.required(Component::Number)?;
 
         Ok(Self {
             gender,
@@ -34,6 +32,10 @@ impl PartOfSpeechParsing for ReciprocalPronounParsing {
         })
     }
 
+    fn to_code(&self) -> String {
+        format!("{}-{}{}{}", Self::part_of_speech(), self.case, self.gender, self.number)
+    }
+
     fn case(&self) -> Option<Case> { Some(self.case) }
     fn gender(&self) -> Option<Gender> { Some(self.gender) }
     fn number(&self) -> Option<Number> { Some(self.number) }
@@ -44,7 +46,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("RecPro-AMP")?,
             GreekWordParsing::ReciprocalPronoun(ReciprocalPronounParsing {
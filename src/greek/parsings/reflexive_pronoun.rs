@@ -1,4 +1,4 @@
-use crate::greek::{components::{case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech, person::Person}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech, person::Person}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ReflexivePronounParsing {
@@ -8,9 +8,9 @@ pub struct ReflexivePronounParsing {
     number: Number,
 }
 
-impl Into<GreekWordParsing> for ReflexivePronounParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::ReflexivePronoun(self)
+impl From<ReflexivePronounParsing> for GreekWordParsing {
+    fn from(value: ReflexivePronounParsing) -> Self {
+        GreekWordParsing::ReflexivePronoun(value)
     }
 }
 
@@ -20,15 +20,12 @@ impl PartOfSpeechParsing for ReflexivePronounParsing {
     }
 
     /// - `RefPro-AF3P`: Case, Gender, Person, Number
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        let mut chars = segments.next()
-            .ok_or_else(|| format!("Case, Gender, Person, Number not included"))?
-            .split_inclusive(|_| true).peekable();
-
-        let case: Case = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Case is required"))?.parse()?;
-        let gender: Gender = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Gender is required"))?.parse()?;
-        let person: Person = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Person is required"))?.parse()?;
-        let number: Number = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Number is required"))?.parse()?;
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        reader.next_segment();
+        let case: Case = reader.required(Component::Case)?;
+        let gender: Gender = reader.required(Component::Gender)?;
+        let person: Person = reader.required(Component::Person)?;
+        let number: Number = reader.required(Component::Number)?;
 
         Ok(Self {
             case,
@@ -38,6 +35,10 @@ impl PartOfSpeechParsing for ReflexivePronounParsing {
         })
     }
 
+    fn to_code(&self) -> String {
+        format!("{}-{}{}{}{}", Self::part_of_speech(), self.case, self.gender, self.person, self.number)
+    }
+
     fn case(&self) -> Option<Case> { Some(self.case) }
     fn gender(&self) -> Option<Gender> { Some(self.gender) }
     fn person(&self) -> Option<Person> { Some(self.person) }
@@ -49,7 +50,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("RefPro-AF3P")?,
             GreekWordParsing::ReflexivePronoun(ReflexivePronounParsing {
@@ -1,4 +1,4 @@
-use crate::greek::{components::{case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech, person::Person}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, case::Case, gender::Gender, number::Number, part_of_speech::PartOfSpeech}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct RelativePronounParsing {
@@ -7,9 +7,9 @@ pub struct RelativePronounParsing {
     number: Number,
 }
 
-impl Into<GreekWordParsing> for RelativePronounParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::RelativePronoun(self)
+impl From<RelativePronounParsing> for GreekWordParsing {
+    fn from(value: RelativePronounParsing) -> Self {
+        GreekWordParsing::RelativePronoun(value)
     }
 }
 
@@ -19,14 +19,11 @@ impl PartOfSpeechParsing for RelativePronounParsing {
     }
 
     /// - `RelPro-AFP`: Case, Gender, Number
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        let mut chars = segments.next()
-            .ok_or_else(|| format!("Case, Gender, Number not included"))?
-            .split_inclusive(|_| true).peekable();
-
-        let case: Case = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Case is required"))?.parse()?;
-        let gender: Gender = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Gender is required"))?.parse()?;
-        let number: Number = chars.next().ok_or_else(|| format!("Personal/Possessive Pronoun: Number is required"))?.parse()?;
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        reader.next_segment();
+        let case: Case = reader.required(Component::Case)?;
+        let gender: Gender = reader.required(Component::Gender)?;
+        let number: Number = reader.required(Component::Number)?;
 
         Ok(Self {
             case,
@@ -35,6 +32,10 @@ impl PartOfSpeechParsing for RelativePronounParsing {
         })
     }
 
+    fn to_code(&self) -> String {
+        format!("{}-{}{}{}", Self::part_of_speech(), self.case, self.gender, self.number)
+    }
+
     fn case(&self) -> Option<Case> { Some(self.case) }
     fn gender(&self) -> Option<Gender> { Some(self.gender) }
     fn number(&self) -> Option<Number> { Some(self.number) }
@@ -45,7 +46,7 @@ mod test {
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
         assert_eq!(
             GreekWordParsing::parse("RelPro-AFP")?,
             GreekWordParsing::RelativePronoun(RelativePronounParsing {
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::greek::{components::{case::Case, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice}, word::{GreekWordParsing, PartOfSpeechParsing}};
+use crate::greek::{components::{Component, ComponentCode, case::Case, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice}, error::GreekParseError, reader::CodeReader, word::{GreekWordParsing, PartOfSpeechParsing}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct VerbParsing {
@@ -19,6 +19,8 @@ pub struct PersonNumber {
     number: Number,
 }
 
+/// The fields each mood requires, see [`VerbMoods::try_from`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum VerbMoods {
     /// I - Indicative
     /// - V-PI-3S
@@ -31,11 +33,10 @@ pub enum VerbMoods {
         number: Number,
     },
     /// M - Imperative
-    /// V-M-2P?
-    /// V-M-2S?
+    /// - V-M-2P and V-M-2S have neither tense nor voice
     Imperative {
-        tense: Tense,
-        voice: Voice,
+        tense: Option<Tense>,
+        voice: Option<Voice>,
         person: Person,
         number: Number,
     },
@@ -69,9 +70,99 @@ pub enum VerbMoods {
     },
 }
 
-impl Into<GreekWordParsing> for VerbParsing {
-    fn into(self) -> GreekWordParsing {
-        GreekWordParsing::Verb(self)
+impl From<VerbMoods> for VerbParsing {
+    fn from(value: VerbMoods) -> Self {
+        let verb = |tense, mood, voice| Self { tense, mood, voice, case: None, gender: None, person: None, number: None };
+        match value {
+            VerbMoods::Indicative { tense, voice, person, number } => Self { person: Some(person), number: Some(number), ..verb(Some(tense), Mood::Indicative, voice) },
+            VerbMoods::Imperative { tense, voice, person, number } => Self { person: Some(person), number: Some(number), ..verb(tense, Mood::Imperative, voice) },
+            VerbMoods::Subjunctive { tense, voice, person, number } => Self { person: Some(person), number: Some(number), ..verb(Some(tense), Mood::Subjunctive, Some(voice)) },
+            VerbMoods::Optative { tense, voice, person, number } => Self { person: Some(person), number: Some(number), ..verb(Some(tense), Mood::Optative, Some(voice)) },
+            VerbMoods::Infinitive { tense, voice } => verb(Some(tense), Mood::Infinitive, Some(voice)),
+            VerbMoods::Participle { tense, voice, case, gender, number } => Self { case: Some(case), gender: Some(gender), number: Some(number), ..verb(Some(tense), Mood::Participle, Some(voice)) },
+        }
+    }
+}
+
+impl TryFrom<VerbParsing> for VerbMoods {
+    type Error = GreekParseError;
+
+    /// Strict: every field the mood requires is present, and no other field is
+    /// - `V-ANA-3S`: an infinitive has no person or number
+    /// - `V-APA-3S`: a participle needs a case and gender, and has no person
+    ///
+    /// Errors are positioned in the canonical code of the verb
+    fn try_from(value: VerbParsing) -> Result<Self, Self::Error> {
+        let VerbParsing { tense, mood, voice, case, gender, person, number } = value;
+        let input = value.to_code();
+        let tense_len = tense.map_or(0, |tense| tense.code().len());
+        let position = |component: Component| match component {
+            Component::Tense => (1, 0),
+            Component::Mood => (1, tense_len),
+            Component::Voice => (1, tense_len + 1),
+            Component::Case => (2, 0),
+            Component::Person => (2, case.is_some() as usize),
+            Component::Gender => (2, case.is_some() as usize + person.is_some() as usize),
+            _ => (2, case.is_some() as usize + person.is_some() as usize + gender.is_some() as usize),
+        };
+        let required = |expected: Component| {
+            let (segment, offset) = position(expected);
+            GreekParseError::Missing { input: input.clone(), segment, offset, expected }
+        };
+        let forbidden = |found: Component, is_some: bool| match is_some {
+            true => {
+                let (segment, offset) = position(found);
+                Err(GreekParseError::Unexpected { input: input.clone(), segment, offset, found })
+            },
+            false => Ok(()),
+        };
+        let person_number = || -> Result<(Person, Number), GreekParseError> {
+            forbidden(Component::Case, case.is_some())?;
+            forbidden(Component::Gender, gender.is_some())?;
+            Ok((person.ok_or_else(|| required(Component::Person))?, number.ok_or_else(|| required(Component::Number))?))
+        };
+
+        Ok(match mood {
+            Mood::Indicative => {
+                let tense = tense.ok_or_else(|| required(Component::Tense))?;
+                let (person, number) = person_number()?;
+                VerbMoods::Indicative { tense, voice, person, number }
+            },
+            Mood::Imperative => {
+                let (person, number) = person_number()?;
+                VerbMoods::Imperative { tense, voice, person, number }
+            },
+            Mood::Subjunctive => {
+                let (tense, voice) = (tense.ok_or_else(|| required(Component::Tense))?, voice.ok_or_else(|| required(Component::Voice))?);
+                let (person, number) = person_number()?;
+                VerbMoods::Subjunctive { tense, voice, person, number }
+            },
+            Mood::Optative => {
+                let (tense, voice) = (tense.ok_or_else(|| required(Component::Tense))?, voice.ok_or_else(|| required(Component::Voice))?);
+                let (person, number) = person_number()?;
+                VerbMoods::Optative { tense, voice, person, number }
+            },
+            Mood::Infinitive => {
+                let (tense, voice) = (tense.ok_or_else(|| required(Component::Tense))?, voice.ok_or_else(|| required(Component::Voice))?);
+                forbidden(Component::Case, case.is_some())?;
+                forbidden(Component::Gender, gender.is_some())?;
+                forbidden(Component::Person, person.is_some())?;
+                forbidden(Component::Number, number.is_some())?;
+                VerbMoods::Infinitive { tense, voice }
+            },
+            Mood::Participle => {
+                let (tense, voice) = (tense.ok_or_else(|| required(Component::Tense))?, voice.ok_or_else(|| required(Component::Voice))?);
+                let (case, gender) = (case.ok_or_else(|| required(Component::Case))?, gender.ok_or_else(|| required(Component::Gender))?);
+                forbidden(Component::Person, person.is_some())?;
+                VerbMoods::Participle { tense, voice, case, gender, number: number.ok_or_else(|| required(Component::Number))? }
+            },
+        })
+    }
+}
+
+impl From<VerbParsing> for GreekWordParsing {
+    fn from(value: VerbParsing) -> Self {
+        GreekWordParsing::Verb(value)
     }
 }
 
@@ -81,41 +172,30 @@ impl PartOfSpeechParsing for VerbParsing {
     }
 
     /// - `RelPro-AFP`: Case, Gender, Number
-    fn parse_segments(mut segments: std::str::Split<'_, &str>) -> Result<Self, String> {
-        // let first = segments.next().ok_or_else(|| format!("Mood not included"))?;
-        let mut chars = segments.next().ok_or_else(|| format!("Mood not included"))?.split_inclusive(|_| true).peekable();
-
-        let tense: Option<Tense> = chars.peek().ok_or_else(|| format!("Verb: Mood is required"))?.parse().ok();
-        if tense.is_some() { chars.next(); }
-        let mood: Mood = chars.next().ok_or_else(|| format!("Verb: Mood is required"))?.parse()?;
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError> {
+        reader.next_segment();
+        // a second tense, like `2A`, is two characters
+        let tense: Option<Tense> = match reader.maybe_chars(2) {
+            Some(tense) => Some(tense),
+            None => reader.optional(Component::Mood)?,
+        };
+        let mood: Mood = reader.required(Component::Mood)?;
         // M/P is at the end so I can just get the rest
-        let voice: Option<Voice> = chars.join("").parse().ok();
-
-        // let second = segments.next();
-        let chars = segments.next().map(|seg| seg.split_inclusive(|_| true).peekable());
+        let voice: Option<Voice> = match reader.is_empty() {
+            true => None,
+            false => Some(reader.required_rest(Component::Voice)?),
+        };
 
         // these are always found together, but I don't know Greek to know if it is technically allowable
-        let (case, gender, person, number) = match chars {
-            Some(mut chars) => {
-                let case: Option<Case> = chars.peek().and_then(|c| c.parse().ok());
-                // let case: Option<Case> = chars.peek().map(|c| c.parse()).transpose()?;
-                if case.is_some() { chars.next(); }
-
-                let person: Option<Person> = chars.peek().and_then(|c| c.parse().ok());
-                // let person: Option<Person> = chars.peek().map(|c| c.parse()).transpose()?;
-                if person.is_some() { chars.next(); }
-
-                let gender: Option<Gender> = chars.peek().and_then(|c| c.parse().ok());
-                // let gender: Option<Gender> = chars.peek().map(|c| c.parse()).transpose()?;
-                if gender.is_some() { chars.next(); }
-
-                let number: Option<Number> = chars.peek().and_then(|c| c.parse().ok());
-                // let number: Option<Number> = chars.peek().map(|c| c.parse()).transpose()?;
-                if person.is_some() { chars.next(); }
-
+        let (case, gender, person, number) = match reader.next_segment() {
+            true => {
+                let case: Option<Case> = reader.maybe();
+                let person: Option<Person> = reader.maybe();
+                let gender: Option<Gender> = reader.maybe();
+                let number: Option<Number> = reader.maybe();
                 (case, gender, person, number)
             },
-            None => (None, None, None, None),
+            false => (None, None, None, None),
         };
 
         Ok(Self {
@@ -129,6 +209,18 @@ impl PartOfSpeechParsing for VerbParsing {
         })
     }
 
+    /// - `V-{Tense}{Mood}{Voice}-{Case}{Person}{Gender}{Number}`, where only the mood is required
+    fn to_code(&self) -> String {
+        let first = [self.tense.map(|t| t.code()), Some(self.mood.code()), self.voice.map(|v| v.code())];
+        let second = [self.case.map(|c| c.code()), self.person.map(|p| p.code()), self.gender.map(|g| g.code()), self.number.map(|n| n.code())];
+        let first = first.into_iter().flatten().join("");
+        let second = second.into_iter().flatten().join("");
+        match second.is_empty() {
+            true => format!("{}-{first}", Self::part_of_speech()),
+            false => format!("{}-{first}-{second}", Self::part_of_speech()),
+        }
+    }
+
     fn case(&self) -> Option<Case> { self.case }
     fn gender(&self) -> Option<Gender> { self.gender }
     fn mood(&self) -> Option<Mood> { Some(self.mood) }
@@ -144,10 +236,12 @@ mod test {
 
     use itertools::Itertools;
 
+    use crate::greek::{query::ParsingQuery, word::ParsingMode};
+
     use super::*;
 
     #[test]
-    fn bsb() -> Result<(), String> {
+    fn bsb() -> Result<(), GreekParseError> {
 
         // V-AIA-1P: Aorist, Indicative, Active - 1 Person, Plural
         assert_eq!(
@@ -270,11 +364,11 @@ mod test {
         Ok(())
     }
 
+    const ALL_VERBS: &[&str] = &[ "V-AIA-3S", "V-AIP-3S", "V-PPM/P-NMS", "V-IIA-3S", "V-APP-GFS", "V-ANA", "V-PPA-NFS", "V-PPA-NMS", "V-APP-GMS", "V-AMA-2S", "V-ASP-2S", "V-APP-NNS", "V-PIA-3S", "V-FIM-3S", "V-FIA-2S", "V-FIA-3S", "V-RIA-3S", "V-ASP-3S", "V-PPA-GMS", "V-FIA-3P", "V-PPM/P-NNS", "V-APP-NMS", "V-AIM-3P", "V-PPA-NMP", "V-AIA-1P", "V-APA-NMS", "V-IIM/P-3S", "V-PIM/P-3S", "V-AIA-3P", "V-RIM/P-3S", "V-PIA-2S", "V-PPM/P-GMS", "V-APP-NMP", "V-AMA-2P", "V-ASA-2P", "V-ASA-1S", "V-APA-NMP", "V-AIP-3P", "V-APA-GMP", "V-PMA-2S", "V-PNA", "V-AIA-1S", "V-ANP", "V-PIA-3P", "V-APA-GMS", "V-PMM/P-2S", "V-RIA-3P", "V-PPM/P-AFS", "V-FIP-3S", "V-PMA-2P", "V-PPM-NMP", "V-IIM/P-3P", "V-PPM/P-AMP", "V-PPA-GFS", "V-PIA-1P", "V-PIA-1S", "V-PPA-NNS", "V-PIM/P-2S", "V-PPA-ANS", "V-PPM/P-ANS", "V-ASM-3P", "V-PPM/P-DNS", "V-ASA-2S", "V-FIA-1S", "V-IIA-3P", "V-PPM/P-DMP", "V-AIM-3S", "V-PPM/P-AMS", "V-PPA-AMP", "V-M-2P", "V-FIP-3P", "V-FIM-3P", "V-RPM/P-NMP", "V-PIA-2P", "V-ASA-3P", "V-PPM/P-NMP", "V-PMM/P-2P", "V-PNM/P", "V-PPM/P-NFS", "V-AMA-3S", "V-ASA-3S", "V-ASM-3S", "V-AIA-2P", "V-PSA-2S", "V-AMP-2S", "V-FIP-2S", "V-RPM/P-AFS", "V-PMA-3S", "V-PPA-DMS", "V-PPA-AMS", "V-ANM", "V-PPA-GMP", "V-ASM-2P", "V-FIM-2P", "V-ASP-3P", "V-PSA-3S", "V-PSM/P-2P", "V-RPA-NMP", "V-PSM/P-2S", "V-AMM-2S", "V-ASP-2P", "V-AMP-3S", "V-PSA-2P", "V-PIM/P-2P", "V-ASA-1P", "V-ASM-1P", "V-FIP-2P", "V-FIA-2P", "V-RIA-2P", "V-PPA-DMP", "V-PSA-3P", "V-RPM/P-NFS", "V-PIM/P-3P", "V-PPM/P-VMP", "V-LIM/P-3S", "V-AIA-2S", "V-PPA-AFS", "V-APM-GFS", "V-APA-DMS", "V-PIM-1P", "V-PPM/P-GMP", "V-RPM/P-AMS", "V-RPA-NMS", "V-RSA-2P", "V-APA-AMS", "V-APA-NFS", "V-ASM-1S", "V-FIP-1S", "V-PIM/P-1S", "V-APP-GNS", "V-PPA-NNP", "V-AMP-2P", "V-APM-NMS", "V-RPA-ANP", "V-AMM-2P", "V-RPM/P-NNS", "V-PMM-2P", "V-RPM/P-NFP", "V-FIM-1S", "V-PPM/P-DNP", "V-AIM-2P", "V-APM-NFP", "V-FIM-2S", "V-PIM-1S", "V-PSM/P-3S", "V-PPA-VMP", "V-RPM/P-VMP", "V-LIA-2P", "V-IIM-3P", "V-APP-NFS", "V-APA-NNS", "V-APA-NNP", "V-LIA-3P", "V-LIA-3S", "V-RPM/P-ANS", "V-RPM/P-ANP", "V-RPM/P-DMS", "V-APP-DFS", "V-APA-DFS", "V-APM-DNP", "V-PIM-3P", "V-RIA-2S", "V-PPM/P-NNP", "V-AIP-1S", "V-PPA-GNP", "V-AMM-3S", "V-RPA-GMP", "V-RPM/P-VFS", "V-AIP-1P", "V-RPM/P-DNP", "V-RPM/P-NNP", "V-APM-GMS", "V-APM-ANP", "V-PPA-ANP", "V-M-2S", "V-RPA-AMP", "V-APA-DMP", "V-PIM-2P", "V-PIM/P-1P", "V-RPM/P-NMS", "V-AIM-2S", "V-RIA-1P", "V-AIP-2P", "V-PPA-DNS", "V-RPM/P-AMP", "V-RPM/P-DMP", "V-RIA-1S", "V-RPM-AMS", "V-APP-ANS", "V-RPM/P-GMP", "V-PPM/P-DMS", "V-IIM-1P", "V-PPA-VFS", "V-RPA-ANS", "V-PMA-3P", "V-PPA-DFP", "V-PPA-NFP", "V-PPA-DNP", "V-APA-NFP", "V-PPM/P-GFP", "V-IIA-2S", "V-LIA-2S", "V-AIM-1S", "V-IIM-1S", "V-PSA-1S", "V-PSA-1P", "V-IIM/P-1S", "V-IIM-2S", "V-RPA-GMS", "V-RPM/P-GMS", "V-PPA-VMS", "V-FIA-1P", "V-FPA-NMS", "V-APM/P-ANP", "V-PPM/P-NFP", "V-PPA-DFS", "V-APP-NFP", "V-RPM-NMS", "V-PPM/P-AFP", "V-PNM", "V-RMM/P-2S", "V-RNM/P", "V-RPA-NNS", "V-RPA-AMS", "V-APA-AFS", "V-IIM-3S", "V-RPA-NFS", "V-APM-GNS", "V-APA-GFS", "V-RPA-AFS", "V-APM-NMP", "V-IIM/P-2P", "V-IIA-1P", "V-AOA-3S", "V-PPA-GNS", "V-PPM/P-ANP", "V-PPM-NMS", "V-PPM-AMS", "V-RPA-DMP", "V-APM-DMP", "V-RPM/P-GNP", "V-RPA-DMS", "V-AIP-2S", "V-POA-3S", "V-PPM/P-DFS", "V-AOM-3S", "V-APP-AMP", "V-RPM/P-DFS", "V-APP-GNP", "V-IIA-2P", "V-APP-GFP", "V-APA-AMP", "V-RIM/P-3P", "V-AOA-3P", "V-PIM-3S", "V-PSM/P-3P", "V-APP-AMS", "V-IIA-1S", "V-RNA", "V-APA-GNS", "V-PMM-2S", "V-RIM/P-2S", "V-ASP-1P", "V-ASP-1S", "V-AMA-3P", "V-APP-ANP", "V-PSM-3S", "V-APM-AMS", "V-PPM-GMS", "V-PMM/P-3P", "V-PPM/P-GNP", "V-PPM-GNP", "V-PMM/P-3S", "V-FIM/P-2P", "V-ASM-2S", "V-FPM-ANS", "V-APM-AMP", "V-APM-AFS", "V-APP-GMP", "V-APM-ANS", "V-RPA-NFP", "V-PPA-GFP", "V-APM-GFP", "V-RPA-GNP", "V-AIM-1P", "V-LIA-1S", "V-RIM/P-1S", "V-PPM/P-GNS", "V-PSM/P-1S", "V-APA-ANP", "V-PPM-AFS", "V-PSM/P-1P", "V-FIM-1P", "V-RIM/P-2P", "V-RIP-1P", "V-LIM-3P", "V-RPM/P-GFP", "V-APM/P-GFS", "V-FNA", "V-PPM/P-GFS", "V-RPA-DNS", "V-RPA-GFS", "V-PPM-DMS", "V-POM/P-1S", "V-PPM-AMP", "V-IIM/P-2S", "V-PPM-NFP", "V-FNM", "V-RPM/P-DFP", "V-PNP", "V-RMM/P-2P", "V-APA-DFP", "V-RPM/P-AFP", "V-PPM-GMP", "V-APA-AFP", "V-FPA-ANP", "V-IIM/P-1P", "V-APM-GMP", "V-RPM/P-VMS", "V-APA-ANS", "V-POA-3P", "V-RIM-2S", "V-POM/P-3S", "V-AOM-1S", "V-LIM-3S", "V-APP-DMS", "V-POM/P-3P", "V-PPM-ANS", "V-PPP-DMP", "V-PPM/P-VMS", "V-FIP-1P", "V-RPA-NNP", "V-AMP-3P", "V-APP-AFS", "V-PPP-NMP", "V-PMP-3S", "V-PPA-AFP", "V-RPM/P-GNS", "V-PPM-DMP", "V-RSA-1P", "V-PIP-3S", "V-PSM-1S", "V-IIP-3P", "V-ANM/P", "V-RSA-1S", "V-PI-3S", "V-PPM-GFS", "V-RPM/P-GFS", "V-RPM/P-DNS", "V-RIM/P-1P", "V-PIP-1S", "V-PPP-GMS", "V-RIM-3S", "V-PPM-NFS", "V-RMA-2P", "V-PPM-NNS", "V-PMP-2P", "V-AOP-3S", "V-PSM-2S", "V-PPM/P-DFP", "V-RSA-2S", "V-PPP-GMP", "V-APP-DNP", "V-FPP-GNP", "V-APM-GNP", "V-APP-NNP", "V-APM-NFS", "V-IIP-1P", "V-FPA-NMP", "V-PPM-GFP", "V-AMM-3P", "V-PPM-GNS", "V-APP-DNS", "V-POA-2P", "V-APM/P-NMS", "V-APM-NNS", "V-PSM-1P", "V-RPM-NMP" ];
+
     #[test]
     fn all_verbs() {
-        let parsings: &[&str] = &[ "V-AIA-3S", "V-AIP-3S", "V-PPM/P-NMS", "V-IIA-3S", "V-APP-GFS", "V-ANA", "V-PPA-NFS", "V-PPA-NMS", "V-APP-GMS", "V-AMA-2S", "V-ASP-2S", "V-APP-NNS", "V-PIA-3S", "V-FIM-3S", "V-FIA-2S", "V-FIA-3S", "V-RIA-3S", "V-ASP-3S", "V-PPA-GMS", "V-FIA-3P", "V-PPM/P-NNS", "V-APP-NMS", "V-AIM-3P", "V-PPA-NMP", "V-AIA-1P", "V-APA-NMS", "V-IIM/P-3S", "V-PIM/P-3S", "V-AIA-3P", "V-RIM/P-3S", "V-PIA-2S", "V-PPM/P-GMS", "V-APP-NMP", "V-AMA-2P", "V-ASA-2P", "V-ASA-1S", "V-APA-NMP", "V-AIP-3P", "V-APA-GMP", "V-PMA-2S", "V-PNA", "V-AIA-1S", "V-ANP", "V-PIA-3P", "V-APA-GMS", "V-PMM/P-2S", "V-RIA-3P", "V-PPM/P-AFS", "V-FIP-3S", "V-PMA-2P", "V-PPM-NMP", "V-IIM/P-3P", "V-PPM/P-AMP", "V-PPA-GFS", "V-PIA-1P", "V-PIA-1S", "V-PPA-NNS", "V-PIM/P-2S", "V-PPA-ANS", "V-PPM/P-ANS", "V-ASM-3P", "V-PPM/P-DNS", "V-ASA-2S", "V-FIA-1S", "V-IIA-3P", "V-PPM/P-DMP", "V-AIM-3S", "V-PPM/P-AMS", "V-PPA-AMP", "V-M-2P", "V-FIP-3P", "V-FIM-3P", "V-RPM/P-NMP", "V-PIA-2P", "V-ASA-3P", "V-PPM/P-NMP", "V-PMM/P-2P", "V-PNM/P", "V-PPM/P-NFS", "V-AMA-3S", "V-ASA-3S", "V-ASM-3S", "V-AIA-2P", "V-PSA-2S", "V-AMP-2S", "V-FIP-2S", "V-RPM/P-AFS", "V-PMA-3S", "V-PPA-DMS", "V-PPA-AMS", "V-ANM", "V-PPA-GMP", "V-ASM-2P", "V-FIM-2P", "V-ASP-3P", "V-PSA-3S", "V-PSM/P-2P", "V-RPA-NMP", "V-PSM/P-2S", "V-AMM-2S", "V-ASP-2P", "V-AMP-3S", "V-PSA-2P", "V-PIM/P-2P", "V-ASA-1P", "V-ASM-1P", "V-FIP-2P", "V-FIA-2P", "V-RIA-2P", "V-PPA-DMP", "V-PSA-3P", "V-RPM/P-NFS", "V-PIM/P-3P", "V-PPM/P-VMP", "V-LIM/P-3S", "V-AIA-2S", "V-PPA-AFS", "V-APM-GFS", "V-APA-DMS", "V-PIM-1P", "V-PPM/P-GMP", "V-RPM/P-AMS", "V-RPA-NMS", "V-RSA-2P", "V-APA-AMS", "V-APA-NFS", "V-ASM-1S", "V-FIP-1S", "V-PIM/P-1S", "V-APP-GNS", "V-PPA-NNP", "V-AMP-2P", "V-APM-NMS", "V-RPA-ANP", "V-AMM-2P", "V-RPM/P-NNS", "V-PMM-2P", "V-RPM/P-NFP", "V-FIM-1S", "V-PPM/P-DNP", "V-AIM-2P", "V-APM-NFP", "V-FIM-2S", "V-PIM-1S", "V-PSM/P-3S", "V-PPA-VMP", "V-RPM/P-VMP", "V-LIA-2P", "V-IIM-3P", "V-APP-NFS", "V-APA-NNS", "V-APA-NNP", "V-LIA-3P", "V-LIA-3S", "V-RPM/P-ANS", "V-RPM/P-ANP", "V-RPM/P-DMS", "V-APP-DFS", "V-APA-DFS", "V-APM-DNP", "V-PIM-3P", "V-RIA-2S", "V-PPM/P-NNP", "V-AIP-1S", "V-PPA-GNP", "V-AMM-3S", "V-RPA-GMP", "V-RPM/P-VFS", "V-AIP-1P", "V-RPM/P-DNP", "V-RPM/P-NNP", "V-APM-GMS", "V-APM-ANP", "V-PPA-ANP", "V-M-2S", "V-RPA-AMP", "V-APA-DMP", "V-PIM-2P", "V-PIM/P-1P", "V-RPM/P-NMS", "V-AIM-2S", "V-RIA-1P", "V-AIP-2P", "V-PPA-DNS", "V-RPM/P-AMP", "V-RPM/P-DMP", "V-RIA-1S", "V-RPM-AMS", "V-APP-ANS", "V-RPM/P-GMP", "V-PPM/P-DMS", "V-IIM-1P", "V-PPA-VFS", "V-RPA-ANS", "V-PMA-3P", "V-PPA-DFP", "V-PPA-NFP", "V-PPA-DNP", "V-APA-NFP", "V-PPM/P-GFP", "V-IIA-2S", "V-LIA-2S", "V-AIM-1S", "V-IIM-1S", "V-PSA-1S", "V-PSA-1P", "V-IIM/P-1S", "V-IIM-2S", "V-RPA-GMS", "V-RPM/P-GMS", "V-PPA-VMS", "V-FIA-1P", "V-FPA-NMS", "V-APM/P-ANP", "V-PPM/P-NFP", "V-PPA-DFS", "V-APP-NFP", "V-RPM-NMS", "V-PPM/P-AFP", "V-PNM", "V-RMM/P-2S", "V-RNM/P", "V-RPA-NNS", "V-RPA-AMS", "V-APA-AFS", "V-IIM-3S", "V-RPA-NFS", "V-APM-GNS", "V-APA-GFS", "V-RPA-AFS", "V-APM-NMP", "V-IIM/P-2P", "V-IIA-1P", "V-AOA-3S", "V-PPA-GNS", "V-PPM/P-ANP", "V-PPM-NMS", "V-PPM-AMS", "V-RPA-DMP", "V-APM-DMP", "V-RPM/P-GNP", "V-RPA-DMS", "V-AIP-2S", "V-POA-3S", "V-PPM/P-DFS", "V-AOM-3S", "V-APP-AMP", "V-RPM/P-DFS", "V-APP-GNP", "V-IIA-2P", "V-APP-GFP", "V-APA-AMP", "V-RIM/P-3P", "V-AOA-3P", "V-PIM-3S", "V-PSM/P-3P", "V-APP-AMS", "V-IIA-1S", "V-RNA", "V-APA-GNS", "V-PMM-2S", "V-RIM/P-2S", "V-ASP-1P", "V-ASP-1S", "V-AMA-3P", "V-APP-ANP", "V-PSM-3S", "V-APM-AMS", "V-PPM-GMS", "V-PMM/P-3P", "V-PPM/P-GNP", "V-PPM-GNP", "V-PMM/P-3S", "V-FIM/P-2P", "V-ASM-2S", "V-FPM-ANS", "V-APM-AMP", "V-APM-AFS", "V-APP-GMP", "V-APM-ANS", "V-RPA-NFP", "V-PPA-GFP", "V-APM-GFP", "V-RPA-GNP", "V-AIM-1P", "V-LIA-1S", "V-RIM/P-1S", "V-PPM/P-GNS", "V-PSM/P-1S", "V-APA-ANP", "V-PPM-AFS", "V-PSM/P-1P", "V-FIM-1P", "V-RIM/P-2P", "V-RIP-1P", "V-LIM-3P", "V-RPM/P-GFP", "V-APM/P-GFS", "V-FNA", "V-PPM/P-GFS", "V-RPA-DNS", "V-RPA-GFS", "V-PPM-DMS", "V-POM/P-1S", "V-PPM-AMP", "V-IIM/P-2S", "V-PPM-NFP", "V-FNM", "V-RPM/P-DFP", "V-PNP", "V-RMM/P-2P", "V-APA-DFP", "V-RPM/P-AFP", "V-PPM-GMP", "V-APA-AFP", "V-FPA-ANP", "V-IIM/P-1P", "V-APM-GMP", "V-RPM/P-VMS", "V-APA-ANS", "V-POA-3P", "V-RIM-2S", "V-POM/P-3S", "V-AOM-1S", "V-LIM-3S", "V-APP-DMS", "V-POM/P-3P", "V-PPM-ANS", "V-PPP-DMP", "V-PPM/P-VMS", "V-FIP-1P", "V-RPA-NNP", "V-AMP-3P", "V-APP-AFS", "V-PPP-NMP", "V-PMP-3S", "V-PPA-AFP", "V-RPM/P-GNS", "V-PPM-DMP", "V-RSA-1P", "V-PIP-3S", "V-PSM-1S", "V-IIP-3P", "V-ANM/P", "V-RSA-1S", "V-PI-3S", "V-PPM-GFS", "V-RPM/P-GFS", "V-RPM/P-DNS", "V-RIM/P-1P", "V-PIP-1S", "V-PPP-GMS", "V-RIM-3S", "V-PPM-NFS", "V-RMA-2P", "V-PPM-NNS", "V-PMP-2P", "V-AOP-3S", "V-PSM-2S", "V-PPM/P-DFP", "V-RSA-2S", "V-PPP-GMP", "V-APP-DNP", "V-FPP-GNP", "V-APM-GNP", "V-APP-NNP", "V-APM-NFS", "V-IIP-1P", "V-FPA-NMP", "V-PPM-GFP", "V-AMM-3P", "V-PPM-GNS", "V-APP-DNS", "V-POA-2P", "V-APM/P-NMS", "V-APM-NNS", "V-PSM-1P", "V-RPM-NMP" ];
-
-        let errors = parsings.iter().filter_map(|input| {
+        let errors = ALL_VERBS.iter().filter_map(|input| {
             let result = GreekWordParsing::parse(input);
             result.is_err().then(|| format!("Code: \"{}\"\nReason: \"{}\"", input, result.unwrap_err()))
         }).collect_vec();
@@ -283,4 +377,86 @@ mod test {
             panic!("{}\nTotal errors: {}", errors.join("\n\n"), errors.len());
         }
     }
+
+    #[test]
+    fn round_trip() -> Result<(), GreekParseError> {
+        for code in ALL_VERBS {
+            assert_eq!(GreekWordParsing::parse(code)?.to_code(), *code);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn strict() -> Result<(), GreekParseError> {
+        for code in ALL_VERBS {
+            let parsing = GreekWordParsing::parse_with(code, ParsingMode::Strict)?;
+            let GreekWordParsing::Verb(verb) = parsing else { panic!("{code} is not a verb") };
+            assert_eq!(VerbParsing::from(VerbMoods::try_from(verb)?), verb);
+        }
+
+        assert_eq!(
+            VerbMoods::try_from(VerbParsing::parse_segments(&mut CodeReader::new("V-APM/P-ANP"))?)?,
+            VerbMoods::Participle { tense: Tense::Aorist, voice: Voice::MiddlePassive, case: Case::Accusative, gender: Gender::Neuter, number: Number::Plural }
+        );
+
+        let rejected = [
+            ("V-ANA-3S", GreekParseError::Unexpected { input: "V-ANA-3S".to_string(), segment: 2, offset: 0, found: Component::Person }),
+            ("V-APA-3S", GreekParseError::Missing { input: "V-APA-3S".to_string(), segment: 2, offset: 0, expected: Component::Case }),
+            ("V-APA-A3MS", GreekParseError::Unexpected { input: "V-APA-A3MS".to_string(), segment: 2, offset: 1, found: Component::Person }),
+            ("V-AIA-ANS", GreekParseError::Unexpected { input: "V-AIA-ANS".to_string(), segment: 2, offset: 0, found: Component::Case }),
+            ("V-AIA", GreekParseError::Missing { input: "V-AIA".to_string(), segment: 2, offset: 0, expected: Component::Person }),
+            ("V-SA-3S", GreekParseError::Missing { input: "V-SA-3S".to_string(), segment: 1, offset: 0, expected: Component::Tense }),
+            ("v-as-3s", GreekParseError::Missing { input: "v-as-3s".to_string(), segment: 1, offset: 2, expected: Component::Voice }),
+            ("V-2AS-3S", GreekParseError::Missing { input: "V-2AS-3S".to_string(), segment: 1, offset: 3, expected: Component::Voice }),
+        ];
+        for (code, error) in rejected {
+            assert_eq!(GreekWordParsing::parse_with(code, ParsingMode::Strict), Err(error), "{code}");
+            assert!(GreekWordParsing::parse_with(code, ParsingMode::Lenient).is_ok(), "{code}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn distinctions() -> Result<(), GreekParseError> {
+        let parsing = GreekWordParsing::parse("V-2AIA-3S")?;
+        assert_eq!(parsing.tense(), Some(Tense::SecondAorist));
+        assert_eq!(parsing.to_code(), "V-2AIA-3S");
+        assert_eq!(parsing.describe(), "Verb - 2nd Aorist Indicative Active - 3rd Person Singular");
+        assert_eq!(GreekWordParsing::from_description(&parsing.describe())?, parsing);
+
+        let cases = [
+            ("V-2AIA-3S", "V-AIA-3S"),
+            ("V-2RPA-NMS", "V-RPA-NMS"),
+            ("V-PID-1S", "V-PIM-1S"),
+            ("V-AIO-3S", "V-AIP-3S"),
+            ("V-PPN-NMP", "V-PPM/P-NMP"),
+            ("V-PIX-3S", "V-PI-3S"),
+            ("V-AIA-3S", "V-AIA-3S"),
+        ];
+        for (code, base) in cases {
+            let parsing = GreekWordParsing::parse_with(code, ParsingMode::Strict)?;
+            assert_eq!(parsing.to_code(), code);
+            assert_eq!(parsing.base(), GreekWordParsing::parse(base)?, "{code}");
+        }
+        assert!(Voice::MiddleDeponent.is_deponent());
+        assert!(Tense::SecondFuture.is_second());
+        assert!(!Tense::Future.is_second());
+        Ok(())
+    }
+
+    #[test]
+    fn query() -> Result<(), GreekParseError> {
+        let participles = ParsingQuery::parse("V-?P?")?;
+        let verbs = ParsingQuery::parse("pos=V")?;
+        for code in ALL_VERBS {
+            let parsing = GreekWordParsing::parse(code)?;
+            assert!(verbs.matches(&parsing), "{code}");
+            assert_eq!(participles.matches(&parsing), parsing.mood() == Some(Mood::Participle) && parsing.tense().is_some() && parsing.voice().is_some(), "{code}");
+            for mood in Mood::ALL {
+                let query = ParsingQuery::parse(&format!("mood={}", mood.code_name()))?;
+                assert_eq!(query.matches(&parsing), parsing.mood() == Some(mood), "{code} {mood}");
+            }
+        }
+        Ok(())
+    }
 }
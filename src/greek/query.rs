@@ -0,0 +1,206 @@
+use std::str::FromStr;
+
+use crate::greek::{components::{Component, ComponentCode, case::Case, comparison::Comparison, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice}, error::GreekParseError, word::GreekWordParsing};
+
+/**
+A question about parsings, like "all aorist passive participles in the genitive"
+
+A query is whitespace separated terms, and a parsing matches if it matches every term.
+- A code pattern, where `?` is any one component and `*` is any number of them: `V-A?P-G??`
+  - Segments after the last one in the pattern can be anything, so `V` is every verb and `V-?P?` every participle
+- A component and its value: `pos=verb tense=aorist voice=passive mood=participle case=genitive`
+  - Values are a code or a name, ignoring case, spaces and punctuation: `voice=M/P` is `voice=middle_or_passive`
+  - `|` separates values that are all allowed, and `none` is the component not being there: `case=genitive|dative`, `voice=none`
+  - `tense=aorist` also matches a 2nd aorist, and `voice=middle` a middle deponent, see [`GreekWordParsing::base`]
+
+Errors are at the term (segment) and the character (offset) within it.
+*/
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParsingQuery {
+    /// The `-` separated segments of each code pattern
+    patterns: Vec<Vec<String>>,
+    conditions: Vec<Condition>,
+}
+
+/// The values a component may have, where `None` is the component not being there
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Condition {
+    component: Component,
+    values: Vec<Option<&'static str>>,
+}
+
+/// Lowercase letters and numbers, so `Middle or Passive`, `middle_or_passive` and `M/P` can all be compared
+fn normalize(value: &str) -> String {
+    value.chars().filter(|c| c.is_alphanumeric()).flat_map(|c| c.to_lowercase()).collect()
+}
+
+fn component(key: &str) -> Option<Component> {
+    Some(match normalize(key).as_str() {
+        "pos" | "partofspeech" => Component::PartOfSpeech,
+        "tense" => Component::Tense,
+        "mood" => Component::Mood,
+        "voice" => Component::Voice,
+        "case" => Component::Case,
+        "gender" => Component::Gender,
+        "person" => Component::Person,
+        "number" => Component::Number,
+        "comparison" | "degree" => Component::Comparison,
+        _ => None?,
+    })
+}
+
+/// The code of the value of a component, by its code or name
+fn value(component: Component, value: &str) -> Option<&'static str> {
+    fn find<T: ComponentCode>(all: &[T], value: &str) -> Option<&'static str> {
+        let value = normalize(value);
+        all.iter()
+            .find(|c| normalize(c.code()) == value || normalize(c.code_name()) == value)
+            .map(|c| c.code())
+    }
+    match component {
+        Component::PartOfSpeech => find(&PartOfSpeech::ALL, value),
+        Component::Tense => find(&Tense::ALL, value),
+        Component::Mood => find(&Mood::ALL, value),
+        Component::Voice => find(&Voice::ALL, value),
+        Component::Case => find(&Case::ALL, value),
+        Component::Gender => find(&Gender::ALL, value),
+        Component::Person => find(&Person::ALL, value),
+        Component::Number => find(&Number::ALL, value),
+        Component::Comparison => find(&Comparison::ALL, value),
+    }
+}
+
+/// Whether a pattern segment matches the codes of a segment, in order
+fn matches_segment(pattern: &str, codes: &[&str]) -> bool {
+    if let Some(rest) = pattern.strip_prefix('*') {
+        return (0..=codes.len()).any(|skip| matches_segment(rest, &codes[skip..]));
+    }
+    match codes.split_first() {
+        None => pattern.is_empty(),
+        Some((code, codes)) => match pattern.strip_prefix('?') {
+            Some(rest) => matches_segment(rest, codes),
+            None => pattern.get(..code.len()).is_some_and(|start| start.eq_ignore_ascii_case(code))
+                && matches_segment(&pattern[code.len()..], codes),
+        },
+    }
+}
+
+impl ParsingQuery {
+    pub fn parse(query: &str) -> Result<Self, GreekParseError> {
+        let mut parsed = Self::default();
+        for (segment, term) in query.split_whitespace().enumerate() {
+            let Some((key, values)) = term.split_once('=') else {
+                parsed.patterns.push(term.split('-').map(String::from).collect());
+                continue;
+            };
+            let component = component(key)
+                .ok_or_else(|| GreekParseError::Unknown { input: query.to_string(), segment, offset: 0, found: key.to_string() })?;
+            let mut offset = key.chars().count() + 1;
+            let mut condition = Condition { component, values: Vec::new() };
+            for found in values.split('|') {
+                let code = match normalize(found).as_str() {
+                    "none" => None,
+                    _ => Some(value(component, found).ok_or_else(|| GreekParseError::invalid(component, found).at(query, segment, offset))?),
+                };
+                condition.values.push(code);
+                offset += found.chars().count() + 1;
+            }
+            parsed.conditions.push(condition);
+        }
+        Ok(parsed)
+    }
+
+    pub fn matches(&self, parsing: &GreekWordParsing) -> bool {
+        let codes = parsing.codes();
+        let base = parsing.base().codes();
+        let conditions = self.conditions.iter().all(|Condition { component, values }| {
+            let index = *component as usize;
+            values.iter().any(|value| *value == codes[index] || *value == base[index])
+        });
+
+        let segments = match parsing.part_of_speech() {
+            PartOfSpeech::Verb => [
+                &[Component::PartOfSpeech][..],
+                &[Component::Tense, Component::Mood, Component::Voice],
+                &[Component::Case, Component::Person, Component::Gender, Component::Number],
+            ],
+            _ => [
+                &[Component::PartOfSpeech][..],
+                &[Component::Case, Component::Gender, Component::Person, Component::Number],
+                &[Component::Comparison],
+            ],
+        };
+        // the segments of the canonical code, which skips those without components
+        let segments = segments.iter()
+            .map(|segment| segment.iter().filter_map(|component| codes[*component as usize]).collect::<Vec<_>>())
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+        let patterns = self.patterns.iter().all(|pattern| {
+            pattern.iter().enumerate().all(|(index, pattern)| matches_segment(pattern, segments.get(index).map(Vec::as_slice).unwrap_or_default()))
+        });
+
+        conditions && patterns
+    }
+}
+
+impl FromStr for ParsingQuery {
+    type Err = GreekParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches() -> Result<(), GreekParseError> {
+        let cases = [
+            ("V-A?P-G??", "V-APP-GMS", true),
+            ("V-A?P-G??", "V-APM/P-GFS", false),
+            ("V-A?P-G??", "V-AIP-3S", false),
+            ("V-?P?", "V-PPM/P-NMP", true),
+            ("V-?P?", "V-M-2P", false),
+            ("V", "V-M-2P", true),
+            ("v-*-2p", "V-PMA-2P", true),
+            ("Adv-C", "Adv-C", true),
+            ("Adv", "Adv-C", true),
+            ("Adv-?", "Adv", false),
+            ("pos=verb tense=aorist voice=passive mood=participle case=genitive", "V-APP-GMS", true),
+            ("tense=aorist", "V-2AIA-3S", true),
+            ("tense=2nd_aorist", "V-AIA-3S", false),
+            ("voice=middle", "V-PID-1S", true),
+            ("voice=M/P", "V-PPM/P-NMP", true),
+            ("voice=middle_or_passive", "V-PPM/P-NMP", true),
+            ("voice=none", "V-PI-3S", true),
+            ("case=genitive|dative pos=ppro", "PPro-D1S", true),
+            ("case=genitive|dative pos=ppro", "PPro-A1S", false),
+            ("person=1 number=Plural", "PPro-G1P", true),
+            ("pos=Personal_Possessive_Pronoun N-?", "PPro-NM3S", false),
+            ("degree=comparative Adj", "Adj-NMS-C", true),
+            ("", "Conj", true),
+        ];
+        for (query, code, expected) in cases {
+            assert_eq!(ParsingQuery::parse(query)?.matches(&GreekWordParsing::parse(code)?), expected, "{query} {code}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            "pos=verb tense=aorst".parse::<ParsingQuery>(),
+            Err(GreekParseError::Invalid { input: "pos=verb tense=aorst".to_string(), segment: 1, offset: 6, expected: Component::Tense, found: "aorst".to_string() })
+        );
+        assert_eq!(
+            "case=genitive|dativ".parse::<ParsingQuery>().map_err(|error| error.to_string()),
+            Err("Invalid Case - 'dativ' at segment 0, offset 14 of 'case=genitive|dativ'".to_string())
+        );
+        assert_eq!(
+            "V-?P? aspect=perfective".parse::<ParsingQuery>(),
+            Err(GreekParseError::Unknown { input: "V-?P? aspect=perfective".to_string(), segment: 1, offset: 0, found: "aspect".to_string() })
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use std::str::{FromStr, Split};
+
+use crate::greek::{components::Component, error::GreekParseError};
+
+/**
+Reads a parsing code one component at a time, keeping track of where each one is
+
+`V-APM/P-ANP` has the segments `V`, `APM/P` and `ANP`.
+Most components are a single character, except the part of speech and the `M/P` voice.
+*/
+#[derive(Clone, Debug)]
+pub struct CodeReader<'a> {
+    input: &'a str,
+    segments: Split<'a, char>,
+    /// The unread characters of the current segment
+    rest: Option<&'a str>,
+    segment: usize,
+    offset: usize,
+}
+
+impl<'a> CodeReader<'a> {
+    /// Starts at the first segment, which is the part of speech
+    pub fn new(input: &'a str) -> Self {
+        let mut segments = input.split('-');
+        let rest = segments.next();
+        Self { input, segments, rest, segment: 0, offset: 0 }
+    }
+
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// Moves to the next segment, and returns `false` if the code has no more segments
+    pub fn next_segment(&mut self) -> bool {
+        self.rest = self.segments.next();
+        self.segment += 1;
+        self.offset = 0;
+        self.rest.is_some()
+    }
+
+    /// Whether the current segment has no more characters
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_none_or(|rest| rest.is_empty())
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        let rest = self.rest?;
+        let next = rest.chars().next()?;
+        Some(&rest[..next.len_utf8()])
+    }
+
+    fn advance(&mut self, read: &str) {
+        self.rest = self.rest.map(|rest| &rest[read.len()..]);
+        self.offset += read.chars().count();
+    }
+
+    /// Reads the next character as a component, which must be there
+    pub fn required<T: FromStr<Err = GreekParseError>>(&mut self, expected: Component) -> Result<T, GreekParseError> {
+        let next = self.peek().ok_or_else(|| self.missing(expected))?;
+        let value = next.parse().map_err(|error: GreekParseError| error.at(self.input, self.segment, self.offset))?;
+        self.advance(next);
+        Ok(value)
+    }
+
+    /// Reads the next character as a component, only if it is one
+    /// - Fails if the segment has no more characters, since a required component must follow
+    pub fn optional<T: FromStr>(&mut self, then: Component) -> Result<Option<T>, GreekParseError> {
+        let next = self.peek().ok_or_else(|| self.missing(then))?;
+        let value = next.parse().ok();
+        if value.is_some() { self.advance(next); }
+        Ok(value)
+    }
+
+    /// Reads the next character as a component, only if there is one and it is valid
+    pub fn maybe<T: FromStr>(&mut self) -> Option<T> {
+        let next = self.peek()?;
+        let value = next.parse().ok();
+        if value.is_some() { self.advance(next); }
+        value
+    }
+
+    /// Reads the next `count` characters as one component, only if there are that many and they are valid
+    pub fn maybe_chars<T: FromStr>(&mut self, count: usize) -> Option<T> {
+        let rest = self.rest?;
+        let end = rest.char_indices().map(|(index, c)| index + c.len_utf8()).nth(count.checked_sub(1)?)?;
+        let value = rest[..end].parse().ok();
+        if value.is_some() { self.advance(&rest[..end]); }
+        value
+    }
+
+    /// Reads the rest of the segment as one component, only if it is valid
+    pub fn maybe_rest<T: FromStr>(&mut self) -> Option<T> {
+        let rest = self.rest?;
+        let value = rest.parse().ok();
+        if value.is_some() { self.advance(rest); }
+        value
+    }
+
+    /// Reads the rest of the segment as one component, which must be there
+    pub fn required_rest<T: FromStr<Err = GreekParseError>>(&mut self, expected: Component) -> Result<T, GreekParseError> {
+        let rest = self.rest.ok_or_else(|| self.missing(expected))?;
+        let value = rest.parse().map_err(|error: GreekParseError| error.at(self.input, self.segment, self.offset))?;
+        self.advance(rest);
+        Ok(value)
+    }
+
+    /// Fails if the current segment has characters that weren't read
+    pub fn end_segment(&self) -> Result<(), GreekParseError> {
+        match self.rest {
+            Some(rest) if !rest.is_empty() => Err(GreekParseError::Unknown { input: self.input.to_string(), segment: self.segment, offset: self.offset, found: rest.to_string() }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fails if the code has characters or segments that weren't read
+    pub fn end(&mut self) -> Result<(), GreekParseError> {
+        self.end_segment()?;
+        match self.next_segment() {
+            true => Err(GreekParseError::Unknown { input: self.input.to_string(), segment: self.segment, offset: 0, found: self.rest.unwrap_or_default().to_string() }),
+            false => Ok(()),
+        }
+    }
+
+    /// A required component is not at the current position
+    pub fn missing(&self, expected: Component) -> GreekParseError {
+        GreekParseError::Missing { input: self.input.to_string(), segment: self.segment, offset: self.offset, expected }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::greek::{error::GreekParseError, tag_sets::GreekTagSet, word::GreekWordParsing};
+
+/// The codes of the BSB tables, which are [`GreekWordParsing`]'s own codes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Bsb;
+
+impl GreekTagSet for Bsb {
+    fn decode(&self, code: &str) -> Result<GreekWordParsing, GreekParseError> {
+        GreekWordParsing::parse(code)
+    }
+
+    fn encode(&self, parsing: &GreekWordParsing) -> Option<String> {
+        Some(parsing.to_code())
+    }
+}
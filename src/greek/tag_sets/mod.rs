@@ -0,0 +1,118 @@
+pub mod bsb;
+pub mod morphgnt;
+pub mod opentext;
+pub mod robinson;
+
+use crate::greek::{components::{Component, ComponentCode, case::Case, comparison::Comparison, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice}, error::GreekParseError, word::{Codes, GreekWordParsing}};
+
+/**
+A scheme of Greek morphology codes
+
+Every tag set reads its codes into the same [`GreekWordParsing`], so codes can be translated between schemes
+and datasets can be joined word by word.
+- [`bsb::Bsb`]: `V-AIA-3S`, `PPro-AF1P`
+- [`robinson::Robinson`]: `V-2AAI-3S`, `P-1NS`
+- [`morphgnt::MorphGnt`]: `V- 3AAI-S--`, `RP ----AP--`
+- [`opentext::OpenText`]: `v.aor.act.ind.3.sg`, `pers.1.acc.pl`
+
+Errors are in the position of the code that was decoded, including those of components that a tag set can read,
+but that do not make a valid parsing, like `art.nom.masc` without a number.
+*/
+pub trait GreekTagSet {
+    /// Reads a code of this tag set
+    fn decode(&self, code: &str) -> Result<GreekWordParsing, GreekParseError>;
+
+    /// Writes a parsing as a code of this tag set, or `None` if the tag set has no way to express it
+    fn encode(&self, parsing: &GreekWordParsing) -> Option<String>;
+
+    /// Rewrites a code of this tag set as a code of another
+    fn translate(&self, code: &str, to: &dyn GreekTagSet) -> Result<Option<String>, GreekParseError> {
+        Ok(to.encode(&self.decode(code)?))
+    }
+}
+
+/// The BSB code of a component, for tag sets that share its letters
+pub(crate) fn canonical(component: Component, code: &str) -> Option<&'static str> {
+    match component {
+        Component::PartOfSpeech => code.parse::<PartOfSpeech>().ok().map(|c| c.code()),
+        Component::Tense => code.parse::<Tense>().ok().map(|c| c.code()),
+        Component::Mood => code.parse::<Mood>().ok().map(|c| c.code()),
+        Component::Voice => code.parse::<Voice>().ok().map(|c| c.code()),
+        Component::Case => code.parse::<Case>().ok().map(|c| c.code()),
+        Component::Gender => code.parse::<Gender>().ok().map(|c| c.code()),
+        Component::Person => code.parse::<Person>().ok().map(|c| c.code()),
+        Component::Number => code.parse::<Number>().ok().map(|c| c.code()),
+        Component::Comparison => code.parse::<Comparison>().ok().map(|c| c.code()),
+    }
+}
+
+/// The BSB code of each component read from the code of a tag set, and where in that code it was read
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Components {
+    pub codes: Codes,
+    /// `(segment, offset)` of each component, in the order of [`Component`]
+    pub positions: [Option<(usize, usize)>; 9],
+}
+
+impl Components {
+    pub fn set(&mut self, component: Component, code: &'static str, segment: usize, offset: usize) {
+        self.codes[component as usize] = Some(code);
+        self.positions[component as usize] = Some((segment, offset));
+    }
+
+    /**
+    Reads the components with [`GreekWordParsing::from_codes`], with its errors placed in `input`
+    - A component that is required but wasn't read is [`GreekParseError::Missing`] where it would be, or at `end`
+    - A component that was read but isn't allowed is [`GreekParseError::Unexpected`] where it was read
+    */
+    pub fn decode(self, input: &str, end: (usize, usize)) -> Result<GreekWordParsing, GreekParseError> {
+        GreekWordParsing::from_codes_at(self.codes).map_err(|(error, at)| {
+            let position = |component: Component| self.positions[component as usize].unwrap_or(end);
+            let missing = match &error {
+                GreekParseError::Missing { expected, .. } | GreekParseError::Invalid { expected, .. } => Some(*expected)
+                    .filter(|expected| self.codes[*expected as usize].is_none_or(str::is_empty)),
+                _ => None,
+            };
+            match (missing, at) {
+                (Some(expected), _) => {
+                    let (segment, offset) = position(expected);
+                    GreekParseError::Missing { input: input.to_string(), segment, offset, expected }
+                },
+                (None, Some(found)) => {
+                    let (segment, offset) = position(found);
+                    GreekParseError::Unexpected { input: input.to_string(), segment, offset, found }
+                },
+                (None, None) => error.at(input, end.0, end.1),
+            }
+        })
+    }
+}
+
+/**
+Reads each character of a segment as the component in the same position of `layout`, starting `offset` characters into the segment
+
+`letter` gives the BSB code of a character, where `Some("")` is a valid character without a component, like `-` in MorphGNT.
+A component that appears twice in `layout` keeps the last value.
+*/
+pub(crate) fn read_letters(
+    components: &mut Components,
+    input: &str,
+    segment: usize,
+    offset: usize,
+    letters: &str,
+    layout: &[Component],
+    letter: impl Fn(Component, char) -> Option<&'static str>,
+) -> Result<(), GreekParseError> {
+    let mut chars = letters.chars();
+    for (offset, component) in layout.iter().enumerate().map(|(index, component)| (offset + index, component)) {
+        let found = chars.next()
+            .ok_or_else(|| GreekParseError::Missing { input: input.to_string(), segment, offset, expected: *component })?;
+        let code = letter(*component, found)
+            .ok_or_else(|| GreekParseError::invalid(*component, &found.to_string()).at(input, segment, offset))?;
+        components.set(*component, code, segment, offset);
+    }
+    match chars.as_str() {
+        "" => Ok(()),
+        rest => Err(GreekParseError::Unknown { input: input.to_string(), segment, offset: offset + layout.len(), found: rest.to_string() }),
+    }
+}
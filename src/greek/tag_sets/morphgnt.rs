@@ -0,0 +1,162 @@
+use crate::greek::{components::{Component, ComponentCode, mood::Mood, part_of_speech::PartOfSpeech, tense::Tense, voice::Voice}, error::GreekParseError, tag_sets::{Components, GreekTagSet, canonical, read_letters}, word::GreekWordParsing};
+
+/**
+The part of speech and parse columns of [MorphGNT](https://github.com/morphgnt/sblgnt), joined by a space
+
+The parse column has 8 characters, which are `-` for a component that is not there:
+person, tense, voice, mood, case, number, gender and degree, such as `V- 3AAI-S--` and `A- ----NSMC`.
+- Tenses are the same as the BSB codes, except `X` perfect and `Y` pluperfect
+- Moods are the same as the BSB codes, except `D` imperative
+
+MorphGNT has fewer parts of speech than the BSB codes, so some are merged when encoding
+- Reflexive and reciprocal pronouns are `RP`, which decodes as [`PartOfSpeech::PersonalPossessivePronoun`]
+- Hebrew, Aramaic and indeclinable words are `N-` without components
+- Interrogative particles are `X-`
+
+Second tenses and deponents are encoded as their [`GreekWordParsing::base`].
+Verbs that are middle or passive can't be encoded, since MorphGNT always picks one.
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MorphGnt;
+
+const COLUMNS: [Component; 8] = [
+    Component::Person, Component::Tense, Component::Voice, Component::Mood,
+    Component::Case, Component::Number, Component::Gender, Component::Comparison,
+];
+
+fn letter(component: Component, letter: char) -> Option<&'static str> {
+    match (component, letter) {
+        (_, '-') => Some(""),
+        (Component::Tense, 'X') => Some(Tense::Perfect.code()),
+        (Component::Tense, 'Y') => Some(Tense::Pluperfect.code()),
+        (Component::Mood, 'D') => Some(Mood::Imperative.code()),
+        (Component::Tense, 'R' | 'L') | (Component::Mood, 'M') | (Component::Voice, 'D' | 'O' | 'N' | 'X') => None,
+        _ => canonical(component, &letter.to_string()),
+    }
+}
+
+impl GreekTagSet for MorphGnt {
+    fn decode(&self, code: &str) -> Result<GreekWordParsing, GreekParseError> {
+        let (part_of_speech, parse) = code.split_once(' ').unwrap_or((code, ""));
+        let part_of_speech = match part_of_speech {
+            "V-" => PartOfSpeech::Verb,
+            "N-" => PartOfSpeech::Noun,
+            "A-" => PartOfSpeech::Adjective,
+            "D-" => PartOfSpeech::Adverb,
+            "RA" => PartOfSpeech::Article,
+            "RD" => PartOfSpeech::DemonstrativePronoun,
+            "RI" => PartOfSpeech::InterrogativeIndefinitePronoun,
+            "RP" => PartOfSpeech::PersonalPossessivePronoun,
+            "RR" => PartOfSpeech::RelativePronoun,
+            "P-" => PartOfSpeech::Preposition,
+            "C-" => PartOfSpeech::Conjunction,
+            "I-" => PartOfSpeech::Interjection,
+            "X-" => PartOfSpeech::Particle,
+            _ => Err(GreekParseError::invalid(Component::PartOfSpeech, part_of_speech).at(code, 0, 0))?,
+        };
+        let mut components = Components::default();
+        read_letters(&mut components, code, 1, 0, parse, &COLUMNS, letter)?;
+        components.set(Component::PartOfSpeech, part_of_speech.code(), 0, 0);
+        components.decode(code, (1, parse.chars().count()))
+    }
+
+    fn encode(&self, parsing: &GreekWordParsing) -> Option<String> {
+        let part_of_speech = match parsing.part_of_speech() {
+            PartOfSpeech::Verb => "V-",
+            PartOfSpeech::Noun | PartOfSpeech::HebrewWord | PartOfSpeech::AramaicWord | PartOfSpeech::Indeclinable => "N-",
+            PartOfSpeech::Adjective => "A-",
+            PartOfSpeech::Adverb => "D-",
+            PartOfSpeech::Article => "RA",
+            PartOfSpeech::DemonstrativePronoun => "RD",
+            PartOfSpeech::InterrogativeIndefinitePronoun => "RI",
+            PartOfSpeech::PersonalPossessivePronoun | PartOfSpeech::ReflexivePronoun | PartOfSpeech::ReciprocalPronoun => "RP",
+            PartOfSpeech::RelativePronoun => "RR",
+            PartOfSpeech::Preposition => "P-",
+            PartOfSpeech::Conjunction => "C-",
+            PartOfSpeech::Interjection => "I-",
+            PartOfSpeech::Particle | PartOfSpeech::InterrogativeParticle => "X-",
+        };
+        let tense = parsing.tense().map(|tense| match tense.base() {
+            Tense::Perfect => "X",
+            Tense::Pluperfect => "Y",
+            tense => tense.code(),
+        });
+        let voice = match parsing.voice().and_then(|voice| voice.base()) {
+            Some(Voice::MiddlePassive) => None?,
+            voice => voice.map(|voice| voice.code()),
+        };
+        let mood = parsing.mood().map(|mood| match mood {
+            Mood::Imperative => "D",
+            mood => mood.code(),
+        });
+        let columns = [
+            parsing.person().map(|c| c.code()), tense, voice, mood,
+            parsing.case().map(|c| c.code()), parsing.number().map(|c| c.code()), parsing.gender().map(|c| c.code()), parsing.comparison().map(|c| c.code()),
+        ];
+        Some(format!("{part_of_speech} {}", columns.map(|column| column.unwrap_or("-")).concat()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::greek::tag_sets::bsb::Bsb;
+
+    use super::*;
+
+    #[test]
+    fn decode() -> Result<(), GreekParseError> {
+        let cases = [
+            ("V- 3AAI-S--", "V-AIA-3S"),
+            ("V- -PAPNSM-", "V-PPA-NMS"),
+            ("V- 2XAD-P--", "V-RMA-2P"),
+            ("V- -AAN----", "V-ANA"),
+            ("N- ----NSF-", "N-NFS"),
+            ("N- --------", "N"),
+            ("A- ----NSMC", "Adj-NMS-C"),
+            ("D- -------S", "Adv-S"),
+            ("RA ----NSM-", "Art-NMS"),
+            ("RP ----GSM-", "PPro-GMS"),
+            ("RP 1---NS--", "PPro-N1S"),
+            ("X- --------", "Prtcl"),
+        ];
+        for (morphgnt, bsb) in cases {
+            assert_eq!(MorphGnt.decode(morphgnt)?, GreekWordParsing::parse(bsb)?, "{morphgnt}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn encode() -> Result<(), GreekParseError> {
+        for code in ["V-AIA-3S", "V-LIM-3S", "V-RPA-NMP", "V-M-2P", "V-PI-3S", "N-NFS", "N", "Adj-NMS-C", "Adj", "Adv-C", "Art-GFP", "DPro-ANP", "IPro-NMS", "RelPro-DFS", "PPro-N1S", "PPro-GM3S", "Prep", "Conj", "I", "Prtcl"] {
+            let parsing = GreekWordParsing::parse(code)?;
+            let morphgnt = MorphGnt.encode(&parsing).unwrap();
+            assert_eq!(MorphGnt.decode(&morphgnt)?, parsing, "{code} {morphgnt}");
+        }
+        assert_eq!(Bsb.translate("V-RIA-3S", &MorphGnt)?, Some("V- 3XAI-S--".to_string()));
+        assert_eq!(Bsb.translate("RefPro-GM3S", &MorphGnt)?, Some("RP 3---GSM-".to_string()));
+        assert_eq!(Bsb.translate("V-PIM/P-3S", &MorphGnt)?, None);
+        assert_eq!(Bsb.translate("V-2AID-3S", &MorphGnt)?, Some("V- 3AMI-S--".to_string()));
+        assert_eq!(Bsb.translate("V-PIX-3S", &MorphGnt)?, Some("V- 3P-I-S--".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            MorphGnt.decode("V- 3RAI-S--"),
+            Err(GreekParseError::Invalid { input: "V- 3RAI-S--".to_string(), segment: 1, offset: 1, expected: Component::Tense, found: "R".to_string() })
+        );
+        assert_eq!(
+            MorphGnt.decode("N- ----NSF"),
+            Err(GreekParseError::Missing { input: "N- ----NSF".to_string(), segment: 1, offset: 7, expected: Component::Comparison })
+        );
+        assert_eq!(
+            MorphGnt.decode("A- ----NS-C"),
+            Err(GreekParseError::Missing { input: "A- ----NS-C".to_string(), segment: 1, offset: 6, expected: Component::Gender })
+        );
+        assert_eq!(
+            MorphGnt.decode("C- ----N---"),
+            Err(GreekParseError::Unexpected { input: "C- ----N---".to_string(), segment: 1, offset: 4, found: Component::Case })
+        );
+    }
+}
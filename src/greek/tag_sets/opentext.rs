@@ -0,0 +1,153 @@
+use once_cell::sync::Lazy;
+
+use crate::greek::{components::{Component, ComponentCode, case::Case, comparison::Comparison, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice}, error::GreekParseError, tag_sets::{Components, GreekTagSet}, word::GreekWordParsing};
+
+/**
+Lower-case, `.` separated tags in the style of the [OpenText.org](http://opentext.org) annotations
+
+The part of speech comes first, followed by the abbreviation of each component, such as
+`v.aor.act.ind.3.sg` and `pers.1.acc.pl`.
+Components are encoded in the order tense, voice, mood, person, case, number, gender and comparison,
+but may be decoded in any order.
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpenText;
+
+const PARTS_OF_SPEECH: [(&str, PartOfSpeech); 19] = [
+    ("v", PartOfSpeech::Verb),
+    ("n", PartOfSpeech::Noun),
+    ("adv", PartOfSpeech::Adverb),
+    ("adj", PartOfSpeech::Adjective),
+    ("art", PartOfSpeech::Article),
+    ("dem", PartOfSpeech::DemonstrativePronoun),
+    ("intr", PartOfSpeech::InterrogativeIndefinitePronoun),
+    ("pers", PartOfSpeech::PersonalPossessivePronoun),
+    ("recip", PartOfSpeech::ReciprocalPronoun),
+    ("rel", PartOfSpeech::RelativePronoun),
+    ("refl", PartOfSpeech::ReflexivePronoun),
+    ("prep", PartOfSpeech::Preposition),
+    ("conj", PartOfSpeech::Conjunction),
+    ("intj", PartOfSpeech::Interjection),
+    ("ptcl", PartOfSpeech::Particle),
+    ("heb", PartOfSpeech::HebrewWord),
+    ("aram", PartOfSpeech::AramaicWord),
+    ("indecl", PartOfSpeech::Indeclinable),
+    ("intptcl", PartOfSpeech::InterrogativeParticle),
+];
+
+/// `(tag, component, code)` of every component, in the order they are encoded
+static TAGS: Lazy<Vec<(&'static str, Component, &'static str)>> = Lazy::new(|| vec![
+    ("pres", Component::Tense, Tense::Present.code()),
+    ("impf", Component::Tense, Tense::Imperfect.code()),
+    ("fut", Component::Tense, Tense::Future.code()),
+    ("aor", Component::Tense, Tense::Aorist.code()),
+    ("perf", Component::Tense, Tense::Perfect.code()),
+    ("plpf", Component::Tense, Tense::Pluperfect.code()),
+    ("aor2", Component::Tense, Tense::SecondAorist.code()),
+    ("fut2", Component::Tense, Tense::SecondFuture.code()),
+    ("perf2", Component::Tense, Tense::SecondPerfect.code()),
+    ("plpf2", Component::Tense, Tense::SecondPluperfect.code()),
+    ("act", Component::Voice, Voice::Active.code()),
+    ("mid", Component::Voice, Voice::Middle.code()),
+    ("pass", Component::Voice, Voice::Passive.code()),
+    ("midpass", Component::Voice, Voice::MiddlePassive.code()),
+    ("middep", Component::Voice, Voice::MiddleDeponent.code()),
+    ("passdep", Component::Voice, Voice::PassiveDeponent.code()),
+    ("midpassdep", Component::Voice, Voice::MiddlePassiveDeponent.code()),
+    ("novoice", Component::Voice, Voice::NoVoice.code()),
+    ("ind", Component::Mood, Mood::Indicative.code()),
+    ("impv", Component::Mood, Mood::Imperative.code()),
+    ("subj", Component::Mood, Mood::Subjunctive.code()),
+    ("opt", Component::Mood, Mood::Optative.code()),
+    ("inf", Component::Mood, Mood::Infinitive.code()),
+    ("ptc", Component::Mood, Mood::Participle.code()),
+    ("1", Component::Person, Person::First.code()),
+    ("2", Component::Person, Person::Second.code()),
+    ("3", Component::Person, Person::Third.code()),
+    ("nom", Component::Case, Case::Nominative.code()),
+    ("voc", Component::Case, Case::Vocative.code()),
+    ("acc", Component::Case, Case::Accusative.code()),
+    ("gen", Component::Case, Case::Genitive.code()),
+    ("dat", Component::Case, Case::Dative.code()),
+    ("sg", Component::Number, Number::Singular.code()),
+    ("pl", Component::Number, Number::Plural.code()),
+    ("masc", Component::Gender, Gender::Masculine.code()),
+    ("fem", Component::Gender, Gender::Feminine.code()),
+    ("neut", Component::Gender, Gender::Neuter.code()),
+    ("comp", Component::Comparison, Comparison::Comparative.code()),
+    ("sup", Component::Comparison, Comparison::Superlative.code()),
+]);
+
+impl GreekTagSet for OpenText {
+    fn decode(&self, code: &str) -> Result<GreekWordParsing, GreekParseError> {
+        let mut segments = code.split('.');
+        let part_of_speech = segments.next().unwrap_or_default();
+        let part_of_speech = PARTS_OF_SPEECH.iter()
+            .find(|(tag, _)| tag.eq_ignore_ascii_case(part_of_speech))
+            .ok_or_else(|| GreekParseError::invalid(Component::PartOfSpeech, part_of_speech).at(code, 0, 0))?
+            .1;
+
+        let mut components = Components::default();
+        components.set(Component::PartOfSpeech, part_of_speech.code(), 0, 0);
+        let mut end = 1;
+        for (segment, tag) in segments.enumerate().map(|(index, tag)| (index + 1, tag)) {
+            let (_, component, value) = TAGS.iter()
+                .find(|(name, _, _)| name.eq_ignore_ascii_case(tag))
+                .ok_or_else(|| GreekParseError::Unknown { input: code.to_string(), segment, offset: 0, found: tag.to_string() })?;
+            if components.codes[*component as usize].is_some() {
+                Err(GreekParseError::Unexpected { input: code.to_string(), segment, offset: 0, found: *component })?;
+            }
+            components.set(*component, value, segment, 0);
+            end = segment + 1;
+        }
+        components.decode(code, (end, 0))
+    }
+
+    fn encode(&self, parsing: &GreekWordParsing) -> Option<String> {
+        let part_of_speech = PARTS_OF_SPEECH.iter().find(|(_, p)| *p == parsing.part_of_speech())?.0;
+        let tags = TAGS.iter()
+            .filter(|(_, component, code)| parsing.codes()[*component as usize] == Some(*code))
+            .map(|(tag, _, _)| *tag);
+        Some(std::iter::once(part_of_speech).chain(tags).collect::<Vec<_>>().join("."))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::greek::tag_sets::{bsb::Bsb, robinson::Robinson};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() -> Result<(), GreekParseError> {
+        for code in ["V-AIA-3S", "V-2AIA-3S", "V-PPM/P-NMP", "V-RPN-NMS", "V-PIX-3S", "V-M-2P", "V-PI-3S", "N-NFS", "N", "Adj-NMS-C", "Adv-S", "PPro-AF1P", "PPro-GM3S", "RefPro-GM3P", "IPro-NMS", "Prtcl", "Heb", "Indec", "IntPrtcl"] {
+            let parsing = GreekWordParsing::parse(code)?;
+            let opentext = OpenText.encode(&parsing).unwrap();
+            assert_eq!(OpenText.decode(&opentext)?, parsing, "{code} {opentext}");
+        }
+        assert_eq!(Bsb.translate("V-AIA-3S", &OpenText)?, Some("v.aor.act.ind.3.sg".to_string()));
+        assert_eq!(Bsb.translate("PPro-A1P", &OpenText)?, Some("pers.1.acc.pl".to_string()));
+        assert_eq!(OpenText.translate("v.3.sg.aor.ind.act", &Robinson)?, Some("V-AAI-3S".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            OpenText.decode("n.nom.sing.fem"),
+            Err(GreekParseError::Unknown { input: "n.nom.sing.fem".to_string(), segment: 2, offset: 0, found: "sing".to_string() })
+        );
+        assert_eq!(
+            OpenText.decode("n.nom.sg.fem.masc"),
+            Err(GreekParseError::Unexpected { input: "n.nom.sg.fem.masc".to_string(), segment: 4, offset: 0, found: Component::Gender })
+        );
+        assert_eq!(
+            OpenText.decode("art.nom.masc"),
+            Err(GreekParseError::Missing { input: "art.nom.masc".to_string(), segment: 3, offset: 0, expected: Component::Number })
+        );
+        assert_eq!(
+            OpenText.decode("conj.nom"),
+            Err(GreekParseError::Unexpected { input: "conj.nom".to_string(), segment: 1, offset: 0, found: Component::Case })
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use crate::greek::{components::{Component, ComponentCode, mood::Mood, part_of_speech::PartOfSpeech, person::Person, voice::Voice}, error::GreekParseError, tag_sets::{Components, GreekTagSet, canonical, read_letters}, word::GreekWordParsing};
+
+/**
+Maurice Robinson's codes, as used by the Byzantine text and the Open Greek New Testament
+
+- Verbs: `V-{tense}{voice}{mood}`, then `-{person}{number}`, or `-{case}{number}{gender}` for participles, such as `V-2AAI-3S` and `V-PAP-NSM`
+- Nominals: `{part of speech}-{case}{number}{gender}`, then `-C` or `-S` for adjectives that compare, such as `N-NSF` and `A-NSM-C`
+- Pronouns: `P-1NS` for the 1st and 2nd person, `P-GSM` for the 3rd, `F-3GSM` for reflexives and `S-1SNSM` for possessives
+- Indeclinables: `N-PRI` is an indeclinable word, `N-OI` and `N-LI` a noun and `A-NUI` an adjective without components
+
+Second tenses like `2A` and the voices `D`, `O`, `N` and `X` are the same as [`Tense`](crate::greek::components::tense::Tense) and [`Voice`],
+and a BSB code without a voice is encoded as `X`.
+Some of Robinson's detail is dropped when decoding
+- The impersonal active voice `Q`, which is read as active
+- Interrogative `I`, indefinite `X` and correlative `K` / `Q` pronouns, which are read as [`PartOfSpeech::InterrogativeIndefinitePronoun`] and [`PartOfSpeech::DemonstrativePronoun`]
+- Suffixes like `-ATT` and `-P`, which are ignored
+
+Possessives like `PPro-AM1S` can't be encoded, since the BSB codes don't give the number of the possessor, nor can verbs without a tense, like `V-M-2P`.
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Robinson;
+
+const CASE_NUMBER_GENDER: &[Component] = &[Component::Case, Component::Number, Component::Gender];
+
+fn letter(component: Component, letter: char) -> Option<&'static str> {
+    match (component, letter) {
+        (Component::Voice, 'E') => Some(Voice::MiddlePassive.code()),
+        (Component::Voice, 'Q') => Some(Voice::Active.code()),
+        // imperative participle
+        (Component::Mood, 'R') => Some(Mood::Participle.code()),
+        _ => canonical(component, &letter.to_string()),
+    }
+}
+
+impl GreekTagSet for Robinson {
+    fn decode(&self, code: &str) -> Result<GreekWordParsing, GreekParseError> {
+        let upper = code.to_uppercase();
+        let segments = upper.split('-').collect::<Vec<_>>();
+        let segment = |index: usize| segments.get(index).copied().unwrap_or_default();
+        let mut components = Components::default();
+        let read = |components: &mut Components, index: usize, layout: &[Component]| read_letters(components, code, index, 0, segment(index), layout, letter);
+
+        let part_of_speech = match segment(0) {
+            "V" => {
+                match segment(1).strip_prefix('2') {
+                    Some(letters) => {
+                        read_letters(&mut components, code, 1, 1, letters, &[Component::Tense, Component::Voice, Component::Mood], letter)?;
+                        let tense = format!("2{}", components.codes[Component::Tense as usize].unwrap_or_default());
+                        let tense = canonical(Component::Tense, &tense)
+                            .ok_or_else(|| GreekParseError::invalid(Component::Tense, &tense).at(code, 1, 0))?;
+                        components.set(Component::Tense, tense, 1, 0);
+                    },
+                    None => read_letters(&mut components, code, 1, 0, segment(1), &[Component::Tense, Component::Voice, Component::Mood], letter)?,
+                };
+                match components.codes[Component::Mood as usize] {
+                    Some("N") => (),
+                    Some("P") => read(&mut components, 2, CASE_NUMBER_GENDER)?,
+                    _ => read(&mut components, 2, &[Component::Person, Component::Number])?,
+                };
+                PartOfSpeech::Verb
+            },
+            "N" => match segment(1) {
+                "PRI" => PartOfSpeech::Indeclinable,
+                "LI" | "OI" => PartOfSpeech::Noun,
+                _ => {
+                    read(&mut components, 1, CASE_NUMBER_GENDER)?;
+                    PartOfSpeech::Noun
+                },
+            },
+            "A" => {
+                if segment(1) != "NUI" {
+                    read(&mut components, 1, CASE_NUMBER_GENDER)?;
+                    if let Some(comparison) = canonical(Component::Comparison, segment(2)) {
+                        components.set(Component::Comparison, comparison, 2, 0);
+                    }
+                }
+                PartOfSpeech::Adjective
+            },
+            "ADV" => {
+                if let Some(comparison) = canonical(Component::Comparison, segment(1)) {
+                    components.set(Component::Comparison, comparison, 1, 0);
+                }
+                PartOfSpeech::Adverb
+            },
+            "T" | "D" | "K" | "R" | "C" | "I" | "X" | "Q" => {
+                read(&mut components, 1, CASE_NUMBER_GENDER)?;
+                match segment(0) {
+                    "T" => PartOfSpeech::Article,
+                    "D" | "K" => PartOfSpeech::DemonstrativePronoun,
+                    "R" => PartOfSpeech::RelativePronoun,
+                    "C" => PartOfSpeech::ReciprocalPronoun,
+                    _ => PartOfSpeech::InterrogativeIndefinitePronoun,
+                }
+            },
+            "P" => {
+                match segment(1).starts_with(|c: char| c.is_ascii_digit()) {
+                    true => read(&mut components, 1, &[Component::Person, Component::Case, Component::Number])?,
+                    false => {
+                        read(&mut components, 1, CASE_NUMBER_GENDER)?;
+                        components.codes[Component::Person as usize] = Some(Person::Third.code());
+                    },
+                };
+                PartOfSpeech::PersonalPossessivePronoun
+            },
+            "F" => {
+                read(&mut components, 1, &[Component::Person, Component::Case, Component::Number, Component::Gender])?;
+                PartOfSpeech::ReflexivePronoun
+            },
+            "S" => {
+                // the person and number of the possessor, then those of the word itself
+                read(&mut components, 1, &[Component::Person, Component::Number, Component::Case, Component::Number, Component::Gender])?;
+                PartOfSpeech::PersonalPossessivePronoun
+            },
+            "PRT" => match segment(1) {
+                "I" => PartOfSpeech::InterrogativeParticle,
+                _ => PartOfSpeech::Particle,
+            },
+            "CONJ" | "COND" => PartOfSpeech::Conjunction,
+            "PREP" => PartOfSpeech::Preposition,
+            "INJ" => PartOfSpeech::Interjection,
+            "HEB" => PartOfSpeech::HebrewWord,
+            "ARAM" => PartOfSpeech::AramaicWord,
+            _ => Err(GreekParseError::invalid(Component::PartOfSpeech, code.split('-').next().unwrap_or_default()).at(code, 0, 0))?,
+        };
+        components.set(Component::PartOfSpeech, part_of_speech.code(), 0, 0);
+        components.decode(code, (segments.len(), 0))
+    }
+
+    fn encode(&self, parsing: &GreekWordParsing) -> Option<String> {
+        let case_number_gender = || Some(format!("{}{}{}", parsing.case()?, parsing.number()?, parsing.gender()?));
+        let comparison = parsing.comparison().map(|comparison| format!("-{comparison}")).unwrap_or_default();
+        Some(match parsing.part_of_speech() {
+            PartOfSpeech::Verb => {
+                let voice = match parsing.voice() {
+                    Some(Voice::MiddlePassive) => "E",
+                    Some(voice) => voice.code(),
+                    None => "X",
+                };
+                let verb = format!("V-{}{voice}{}", parsing.tense()?, parsing.mood()?);
+                match parsing.mood()? {
+                    Mood::Infinitive => verb,
+                    Mood::Participle => format!("{verb}-{}", case_number_gender()?),
+                    _ => format!("{verb}-{}{}", parsing.person()?, parsing.number()?),
+                }
+            },
+            PartOfSpeech::Noun => match parsing.case() {
+                Some(_) => format!("N-{}", case_number_gender()?),
+                None => "N-OI".to_string(),
+            },
+            PartOfSpeech::Adjective => match parsing.case() {
+                Some(_) => format!("A-{}{comparison}", case_number_gender()?),
+                None => "A-NUI".to_string(),
+            },
+            PartOfSpeech::Adverb => format!("ADV{comparison}"),
+            PartOfSpeech::Article => format!("T-{}", case_number_gender()?),
+            PartOfSpeech::DemonstrativePronoun => format!("D-{}", case_number_gender()?),
+            PartOfSpeech::InterrogativeIndefinitePronoun => format!("I-{}", case_number_gender()?),
+            PartOfSpeech::ReciprocalPronoun => format!("C-{}", case_number_gender()?),
+            PartOfSpeech::RelativePronoun => format!("R-{}", case_number_gender()?),
+            PartOfSpeech::ReflexivePronoun => format!("F-{}{}", parsing.person()?, case_number_gender()?),
+            PartOfSpeech::PersonalPossessivePronoun => match (parsing.person(), parsing.gender()) {
+                (Some(Person::First | Person::Second), None) => format!("P-{}{}{}", parsing.person()?, parsing.case()?, parsing.number()?),
+                (Some(Person::Third) | None, Some(_)) => format!("P-{}", case_number_gender()?),
+                _ => None?,
+            },
+            PartOfSpeech::Preposition => "PREP".to_string(),
+            PartOfSpeech::Conjunction => "CONJ".to_string(),
+            PartOfSpeech::Interjection => "INJ".to_string(),
+            PartOfSpeech::Particle => "PRT".to_string(),
+            PartOfSpeech::InterrogativeParticle => "PRT-I".to_string(),
+            PartOfSpeech::HebrewWord => "HEB".to_string(),
+            PartOfSpeech::AramaicWord => "ARAM".to_string(),
+            PartOfSpeech::Indeclinable => "N-PRI".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::greek::tag_sets::bsb::Bsb;
+
+    use super::*;
+
+    #[test]
+    fn decode() -> Result<(), GreekParseError> {
+        let cases = [
+            ("V-2AAI-3S", "V-2AIA-3S"),
+            ("V-AAI-3S", "V-AIA-3S"),
+            ("V-2RAI-3S", "V-2RIA-3S"),
+            ("V-ADI-3S", "V-AID-3S"),
+            ("V-FOI-3S", "V-FIO-3S"),
+            ("V-PAP-NSM", "V-PPA-NMS"),
+            ("V-PEP-NPM", "V-PPM/P-NMP"),
+            ("V-PNI-3S", "V-PIN-3S"),
+            ("V-PXI-3S", "V-PIX-3S"),
+            ("V-PQI-3S", "V-PIA-3S"),
+            ("V-AAN", "V-ANA"),
+            ("N-NSF", "N-NFS"),
+            ("N-GSM-P", "N-GMS"),
+            ("N-PRI", "Indec"),
+            ("A-NSM-C", "Adj-NMS-C"),
+            ("A-NUI", "Adj"),
+            ("T-NSM", "Art-NMS"),
+            ("P-1NS", "PPro-N1S"),
+            ("P-GSM", "PPro-GM3S"),
+            ("S-1SNSM", "PPro-NM1S"),
+            ("F-3GSM", "RefPro-GM3S"),
+            ("X-NSM", "IPro-NMS"),
+            ("ADV-S", "Adv-S"),
+            ("PRT-I", "IntPrtcl"),
+            ("COND", "Conj"),
+            ("v-2aai-3s", "V-2AIA-3S"),
+        ];
+        for (robinson, bsb) in cases {
+            assert_eq!(Robinson.decode(robinson)?, GreekWordParsing::parse(bsb)?, "{robinson}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn encode() -> Result<(), GreekParseError> {
+        for code in ["V-AIA-3S", "V-2AIA-3S", "V-2RPA-NMP", "V-PPM/P-NMP", "V-PIN-3S", "V-AIO-3S", "V-PIX-3S", "V-ANA", "N-NFS", "N", "Indec", "Adj-NMS-C", "Adj", "Adv-C", "Art-GFP", "DPro-ANP", "IPro-NMS", "RecPro-AMP", "RelPro-DFS", "RefPro-GM3P", "PPro-N1S", "PPro-GM3S", "Prep", "Conj", "I", "Prtcl", "IntPrtcl", "Heb", "Aram"] {
+            let parsing = GreekWordParsing::parse(code)?;
+            let robinson = Robinson.encode(&parsing).unwrap();
+            assert_eq!(Robinson.decode(&robinson)?, parsing, "{code} {robinson}");
+        }
+        assert_eq!(Bsb.translate("V-AIA-3S", &Robinson)?, Some("V-AAI-3S".to_string()));
+        assert_eq!(Bsb.translate("V-PI-3S", &Robinson)?, Some("V-PXI-3S".to_string()));
+        assert_eq!(Robinson.decode("V-2AAI-3S")?.base(), GreekWordParsing::parse("V-AIA-3S")?);
+        assert_eq!(Bsb.translate("PPro-NFS", &Robinson)?, Some("P-NSF".to_string()));
+        assert_eq!(Bsb.translate("PPro-AM1S", &Robinson)?, None);
+        assert_eq!(Bsb.translate("V-M-2P", &Robinson)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Robinson.decode("V-2PAI-3S"),
+            Err(GreekParseError::Invalid { input: "V-2PAI-3S".to_string(), segment: 1, offset: 0, expected: Component::Tense, found: "2P".to_string() })
+        );
+        assert_eq!(
+            Robinson.decode("V-2AZI-3S"),
+            Err(GreekParseError::Invalid { input: "V-2AZI-3S".to_string(), segment: 1, offset: 2, expected: Component::Voice, found: "Z".to_string() })
+        );
+        assert_eq!(
+            Robinson.decode("N-NS"),
+            Err(GreekParseError::Missing { input: "N-NS".to_string(), segment: 1, offset: 2, expected: Component::Gender })
+        );
+        assert_eq!(
+            Robinson.decode("Z-NSM").map_err(|error| error.to_string()),
+            Err("Invalid Part of Speech - 'Z' at segment 0, offset 0 of 'Z-NSM'".to_string())
+        );
+    }
+}
@@ -1,13 +1,20 @@
-use std::{fmt::Display, str::{FromStr, Split}};
+use std::{fmt::Display, str::FromStr};
 
-use crate::greek::{components::{case::Case, comparison::Comparison, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice}, parsings::{adjective::AdjectiveParsing, adverb::AdverbParsing, aramaic_word::AramaicWordParsing, article::ArticleParsing, conjunction::ConjunctionParsing, demonstrative_pronoun::DemonstrativePronounParsing, hebrew_word::HebrewWordParsing, interjection::InterjectionParsing, interrogative_indefinite_pronoun::InterrogativeIndefinitePronounParsing, noun::NounParsing, particle::ParticleParsing, personal_possessive_pronoun::PersonalPossessivePronounParsing, preposition::PrepositionParsing, reciprocal_pronoun::ReciprocalPronounParsing, reflexive_pronoun::ReflexivePronounParsing, relative_pronoun::RelativePronounParsing, verb::VerbParsing}};
+use serde::{Deserialize, Serialize};
+
+use crate::greek::{description::{self, DescriptionOrder}, error::GreekParseError, reader::CodeReader, components::{Component, ComponentCode, case::Case, comparison::Comparison, gender::Gender, mood::Mood, number::Number, part_of_speech::PartOfSpeech, person::Person, tense::Tense, voice::Voice}, parsings::{adjective::AdjectiveParsing, adverb::AdverbParsing, aramaic_word::AramaicWordParsing, article::ArticleParsing, conjunction::ConjunctionParsing, demonstrative_pronoun::DemonstrativePronounParsing, hebrew_word::HebrewWordParsing, indeclinable::IndeclinableParsing, interjection::InterjectionParsing, interrogative_indefinite_pronoun::InterrogativeIndefinitePronounParsing, interrogative_particle::InterrogativeParticleParsing, noun::NounParsing, particle::ParticleParsing, personal_possessive_pronoun::PersonalPossessivePronounParsing, preposition::PrepositionParsing, reciprocal_pronoun::ReciprocalPronounParsing, reflexive_pronoun::ReflexivePronounParsing, relative_pronoun::RelativePronounParsing, verb::{VerbMoods, VerbParsing}}};
 
 pub trait PartOfSpeechParsing: Sized {
     fn part_of_speech() -> PartOfSpeech;
     fn get_part_of_speech(&self) -> PartOfSpeech {
         Self::part_of_speech()
     }
-    fn parse_segments(segments: Split<'_, &str>) -> Result<Self, String>;
+    /// Reads the segments after the part of speech
+    fn parse_segments(reader: &mut CodeReader) -> Result<Self, GreekParseError>;
+    /// The canonical code, such that parsing it gives back the same value
+    fn to_code(&self) -> String {
+        Self::part_of_speech().code().to_string()
+    }
 
     fn case(&self) -> Option<Case> { None }
     fn comparison(&self) -> Option<Comparison> { None }
@@ -19,16 +26,32 @@ pub trait PartOfSpeechParsing: Sized {
     fn voice(&self) -> Option<Voice> { None }
 }
 
+/// The code of each component, in the order of [`Component`], as read by [`GreekWordParsing::from_codes`]
+pub(crate) type Codes = [Option<&'static str>; 9];
+
+/// How strictly [`GreekWordParsing::parse_with`] checks the components of a code
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParsingMode {
+    /// Any component that parses is kept, like [`GreekWordParsing::parse`]
+    #[default]
+    Lenient,
+    /// Verbs must have exactly the components their mood requires, see [`VerbMoods::try_from`]
+    Strict,
+}
+
 /// Resources:
 ///
 /// Format: `Part of Speech  –  Person, Tense, Mood, Voice  –  Case, Number, Gender, Comparison`
 ///
-/// Bible hub has a different order
+/// Bible hub has a different order, see [`DescriptionOrder`]
 /// - https://biblehub.com/abbrev.htm
 /// - https://biblehub.com/grammar/
 /// - https://accordancefiles2.com/helpfiles/14-Win/win14/content/topics/04_gswa/greek_tag_code_tables.htm
 /// - https://help.olivetree.com/hc/en-us/articles/360004615912-NA28-Parsings-Guide
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Serialized as each of its components by name, see [`ParsingComponents`], or as its code with [`as_code`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(into = "ParsingComponents", try_from = "ParsingComponents")]
 pub enum GreekWordParsing {
     Adjective(AdjectiveParsing),
     Adverb(AdverbParsing),
@@ -38,11 +61,8 @@ pub enum GreekWordParsing {
     DemonstrativePronoun(DemonstrativePronounParsing),
     HebrewWord(HebrewWordParsing),
     Interjection(InterjectionParsing),
-    /// Not sure what this is.
-    /// Maybe Indeclinable?
-    Indec,
-    /// Not sure what this is.
-    IntPrtcl,
+    Indeclinable(IndeclinableParsing),
+    InterrogativeParticle(InterrogativeParticleParsing),
     InterrogativeIndefinitePronoun(InterrogativeIndefinitePronounParsing),
     Noun(NounParsing),
     Particle(ParticleParsing),
@@ -55,39 +75,135 @@ pub enum GreekWordParsing {
 }
 
 impl GreekWordParsing {
-    pub fn parse(input: &str) -> Result<GreekWordParsing, String> {
-        let mut segments = input.split("-");
-        let part_of_speech = segments.next().ok_or_else(|| format!("Part of Speech not included"))?;
-        match part_of_speech {
-            "Indec" => return Ok(GreekWordParsing::Indec),
-            "IntPrtcl" => return Ok(GreekWordParsing::IntPrtcl),
-            _ => ()
-        };
-
-        let part_of_speech: PartOfSpeech = part_of_speech.parse()?;
-        Ok(match part_of_speech {
-            PartOfSpeech::Adjective => AdjectiveParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::Adverb => AdverbParsing::parse_segments(segments)?.into(),
+    pub fn parse(input: &str) -> Result<GreekWordParsing, GreekParseError> {
+        let mut reader = CodeReader::new(input);
+        let part_of_speech: PartOfSpeech = reader.required_rest(Component::PartOfSpeech)?;
+        let parsing = match part_of_speech {
+            PartOfSpeech::Adjective => AdjectiveParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::Adverb => AdverbParsing::parse_segments(&mut reader)?.into(),
             PartOfSpeech::AramaicWord => AramaicWordParsing.into(),
-            PartOfSpeech::Article => ArticleParsing::parse_segments(segments)?.into(),
+            PartOfSpeech::Article => ArticleParsing::parse_segments(&mut reader)?.into(),
             PartOfSpeech::Conjunction => ConjunctionParsing.into(),
-            PartOfSpeech::DemonstrativePronoun => DemonstrativePronounParsing::parse_segments(segments)?.into(),
+            PartOfSpeech::DemonstrativePronoun => DemonstrativePronounParsing::parse_segments(&mut reader)?.into(),
             PartOfSpeech::HebrewWord => HebrewWordParsing.into(),
+            PartOfSpeech::Indeclinable => IndeclinableParsing::parse_segments(&mut reader)?.into(),
             PartOfSpeech::Interjection => InterjectionParsing.into(),
-            PartOfSpeech::InterrogativeIndefinitePronoun => InterrogativeIndefinitePronounParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::Noun => NounParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::Particle => ParticleParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::PersonalPossessivePronoun => PersonalPossessivePronounParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::Preposition => PrepositionParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::ReciprocalPronoun => ReciprocalPronounParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::ReflexivePronoun => ReflexivePronounParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::RelativePronoun => RelativePronounParsing::parse_segments(segments)?.into(),
-            PartOfSpeech::Verb => VerbParsing::parse_segments(segments)?.into(),
+            PartOfSpeech::InterrogativeIndefinitePronoun => InterrogativeIndefinitePronounParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::InterrogativeParticle => InterrogativeParticleParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::Noun => NounParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::Particle => ParticleParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::PersonalPossessivePronoun => PersonalPossessivePronounParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::Preposition => PrepositionParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::ReciprocalPronoun => ReciprocalPronounParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::ReflexivePronoun => ReflexivePronounParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::RelativePronoun => RelativePronounParsing::parse_segments(&mut reader)?.into(),
+            PartOfSpeech::Verb => VerbParsing::parse_segments(&mut reader)?.into(),
+        };
+        reader.end()?;
+        Ok(parsing)
+    }
+
+    pub fn parse_with(input: &str, mode: ParsingMode) -> Result<GreekWordParsing, GreekParseError> {
+        let parsing = Self::parse(input)?;
+        if mode == ParsingMode::Strict
+            && let GreekWordParsing::Verb(verb_parsing) = parsing
+        {
+            VerbMoods::try_from(verb_parsing).map_err(|error| {
+                let (segment, offset) = (error.segment(), error.offset());
+                error.at(input, segment, offset)
+            })?;
+        }
+        Ok(parsing)
+    }
+
+    /// The canonical code for this parsing, e.g. `V-APM/P-ANP`
+    ///
+    /// Parsing is case-insensitive, so this also normalizes input like `v-apm/p-anp`.
+    pub fn to_code(&self) -> String {
+        match self {
+            GreekWordParsing::Adjective(adjective_parsing) => adjective_parsing.to_code(),
+            GreekWordParsing::Adverb(adverb_parsing) => adverb_parsing.to_code(),
+            GreekWordParsing::AramaicWord(aramaic_word_parsing) => aramaic_word_parsing.to_code(),
+            GreekWordParsing::Article(article_parsing) => article_parsing.to_code(),
+            GreekWordParsing::Conjunction(conjunction_parsing) => conjunction_parsing.to_code(),
+            GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.to_code(),
+            GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.to_code(),
+            GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.to_code(),
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.to_code(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.to_code(),
+            GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.to_code(),
+            GreekWordParsing::Noun(noun_parsing) => noun_parsing.to_code(),
+            GreekWordParsing::Particle(particle_parsing) => particle_parsing.to_code(),
+            GreekWordParsing::PersonalPossessivePronoun(personal_possessive_pronoun_parsing) => personal_possessive_pronoun_parsing.to_code(),
+            GreekWordParsing::Preposition(preposition_parsing) => preposition_parsing.to_code(),
+            GreekWordParsing::ReciprocalPronoun(reciprocal_pronoun_parsing) => reciprocal_pronoun_parsing.to_code(),
+            GreekWordParsing::ReflexivePronoun(reflexive_pronoun_parsing) => reflexive_pronoun_parsing.to_code(),
+            GreekWordParsing::RelativePronoun(relative_pronoun_parsing) => relative_pronoun_parsing.to_code(),
+            GreekWordParsing::Verb(verb_parsing) => verb_parsing.to_code(),
+        }
+    }
+
+    /// The long-form description in the style of the workbook's "Parsing" column
+    ///
+    /// `V-APM/P-ANP` is `Verb - Aorist Participle Middle or Passive - Accusative Neuter Plural`
+    pub fn describe(&self) -> String {
+        self.describe_with(&DescriptionOrder::BSB)
+    }
+
+    /// The long-form description, listing components in the given order
+    pub fn describe_with(&self, order: &DescriptionOrder) -> String {
+        description::describe(self, order)
+    }
+
+    /// Reads a description from [`GreekWordParsing::describe_with`], in any order
+    pub fn from_description(description: &str) -> Result<GreekWordParsing, GreekParseError> {
+        description::parse_description(description)
+    }
+
+    /// Assembles the canonical code from the code of each component, and reads it
+    /// - Errors are in the position of the assembled code
+    pub(crate) fn from_codes(codes: Codes) -> Result<GreekWordParsing, GreekParseError> {
+        Self::parse(&assemble(&codes).0)
+    }
+
+    /// [`GreekWordParsing::from_codes`], with the component that is at the position of the error in the assembled code, if any
+    pub(crate) fn from_codes_at(codes: Codes) -> Result<GreekWordParsing, (GreekParseError, Option<Component>)> {
+        let (code, layout) = assemble(&codes);
+        Self::parse(&code).map_err(|error| {
+            let component = layout.iter()
+                .find(|(segment, offset, _)| (*segment, *offset) == (error.segment(), error.offset()))
+                .map(|(_, _, component)| *component);
+            (error, component)
         })
     }
 
-    pub fn part_of_speech(&self) -> Option<PartOfSpeech> {
-        Some(match self {
+    /// The code of each component, as read by [`GreekWordParsing::from_codes`]
+    pub(crate) fn codes(&self) -> Codes {
+        [
+            Some(self.part_of_speech().code()),
+            self.tense().map(|c| c.code()),
+            self.mood().map(|c| c.code()),
+            self.voice().map(|c| c.code()),
+            self.case().map(|c| c.code()),
+            self.gender().map(|c| c.code()),
+            self.person().map(|c| c.code()),
+            self.number().map(|c| c.code()),
+            self.comparison().map(|c| c.code()),
+        ]
+    }
+
+    /// The parsing without second tenses and deponent voices, see [`Tense::base`] and [`Voice::base`]
+    ///
+    /// Only some tag sets make these distinctions, so this is how parsings from different tag sets are compared.
+    pub fn base(&self) -> GreekWordParsing {
+        let mut codes = self.codes();
+        codes[Component::Tense as usize] = self.tense().map(|tense| tense.base().code());
+        codes[Component::Voice as usize] = self.voice().and_then(|voice| voice.base()).map(|voice| voice.code());
+        Self::from_codes(codes).expect("a parsing is still valid without its distinctions")
+    }
+
+    pub fn part_of_speech(&self) -> PartOfSpeech {
+        match self {
             GreekWordParsing::Adjective(adjective_parsing) => adjective_parsing.get_part_of_speech(),
             GreekWordParsing::Adverb(adverb_parsing) => adverb_parsing.get_part_of_speech(),
             GreekWordParsing::AramaicWord(aramaic_word_parsing) => aramaic_word_parsing.get_part_of_speech(),
@@ -96,8 +212,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.get_part_of_speech(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.get_part_of_speech(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.get_part_of_speech(),
-            GreekWordParsing::Indec => None?,
-            GreekWordParsing::IntPrtcl => None?,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.get_part_of_speech(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.get_part_of_speech(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.get_part_of_speech(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.get_part_of_speech(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.get_part_of_speech(),
@@ -107,7 +223,7 @@ impl GreekWordParsing {
             GreekWordParsing::ReflexivePronoun(reflexive_pronoun_parsing) => reflexive_pronoun_parsing.get_part_of_speech(),
             GreekWordParsing::RelativePronoun(relative_pronoun_parsing) => relative_pronoun_parsing.get_part_of_speech(),
             GreekWordParsing::Verb(verb_parsing) => verb_parsing.get_part_of_speech(),
-        })
+        }
     }
 
 
@@ -121,8 +237,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.case(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.case(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.case(),
-            GreekWordParsing::Indec => None,
-            GreekWordParsing::IntPrtcl => None,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.case(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.case(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.case(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.case(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.case(),
@@ -145,8 +261,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.comparison(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.comparison(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.comparison(),
-            GreekWordParsing::Indec => None,
-            GreekWordParsing::IntPrtcl => None,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.comparison(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.comparison(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.comparison(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.comparison(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.comparison(),
@@ -169,8 +285,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.gender(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.gender(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.gender(),
-            GreekWordParsing::Indec => None,
-            GreekWordParsing::IntPrtcl => None,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.gender(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.gender(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.gender(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.gender(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.gender(),
@@ -193,8 +309,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.mood(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.mood(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.mood(),
-            GreekWordParsing::Indec => None,
-            GreekWordParsing::IntPrtcl => None,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.mood(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.mood(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.mood(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.mood(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.mood(),
@@ -217,8 +333,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.number(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.number(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.number(),
-            GreekWordParsing::Indec => None,
-            GreekWordParsing::IntPrtcl => None,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.number(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.number(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.number(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.number(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.number(),
@@ -241,8 +357,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.person(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.person(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.person(),
-            GreekWordParsing::Indec => None,
-            GreekWordParsing::IntPrtcl => None,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.person(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.person(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.person(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.person(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.person(),
@@ -265,8 +381,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.tense(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.tense(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.tense(),
-            GreekWordParsing::Indec => None,
-            GreekWordParsing::IntPrtcl => None,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.tense(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.tense(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.tense(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.tense(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.tense(),
@@ -289,8 +405,8 @@ impl GreekWordParsing {
             GreekWordParsing::DemonstrativePronoun(demonstrative_pronoun_parsing) => demonstrative_pronoun_parsing.voice(),
             GreekWordParsing::HebrewWord(hebrew_word_parsing) => hebrew_word_parsing.voice(),
             GreekWordParsing::Interjection(interjection_parsing) => interjection_parsing.voice(),
-            GreekWordParsing::Indec => None,
-            GreekWordParsing::IntPrtcl => None,
+            GreekWordParsing::Indeclinable(indeclinable_parsing) => indeclinable_parsing.voice(),
+            GreekWordParsing::InterrogativeParticle(interrogative_particle_parsing) => interrogative_particle_parsing.voice(),
             GreekWordParsing::InterrogativeIndefinitePronoun(interrogative_indefinite_pronoun_parsing) => interrogative_indefinite_pronoun_parsing.voice(),
             GreekWordParsing::Noun(noun_parsing) => noun_parsing.voice(),
             GreekWordParsing::Particle(particle_parsing) => particle_parsing.voice(),
@@ -304,45 +420,265 @@ impl GreekWordParsing {
     }
 }
 
+/// The canonical code of the codes of each component, with the `(segment, offset, component)` of each component in it
+fn assemble(codes: &Codes) -> (String, Vec<(usize, usize, Component)>) {
+    let verb = codes[Component::PartOfSpeech as usize] == Some(PartOfSpeech::Verb.code());
+    let groups: [&[Component]; 3] = match verb {
+        true => [&[Component::PartOfSpeech], &[Component::Tense, Component::Mood, Component::Voice], &[Component::Case, Component::Person, Component::Gender, Component::Number]],
+        false => [&[Component::PartOfSpeech], &[Component::Case, Component::Gender, Component::Person, Component::Number], &[Component::Comparison]],
+    };
+
+    let mut segments: Vec<String> = Vec::new();
+    let mut layout = Vec::new();
+    for (index, group) in groups.into_iter().enumerate() {
+        let mut segment = String::new();
+        for component in group {
+            if let Some(code) = codes[*component as usize].filter(|code| !code.is_empty()) {
+                layout.push((segments.len(), segment.chars().count(), *component));
+                segment.push_str(code);
+            }
+        }
+        // a verb always has its tense, mood and voice segment
+        if !segment.is_empty() || (verb && index == 1) {
+            segments.push(segment);
+        }
+    }
+    (segments.join("-"), layout)
+}
 
+impl Display for GreekWordParsing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_code())
+    }
+}
+
+impl FromStr for GreekWordParsing {
+    type Err = GreekParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
 
+/**
+The JSON shape of a [`GreekWordParsing`]: the part of speech and each component it has, by [`ComponentCode::code_name`]
+
+`V-AIA-3S` is `{"part_of_speech":"Verb","tense":"Aorist","mood":"Indicative","voice":"Active","person":"3rd Person","number":"Singular"}`
+- Components may also be read by their code: `{"part_of_speech":"V","tense":"A", ...}`
+- A component the part of speech doesn't have is an error, rather than being dropped
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ParsingComponents {
+    pub part_of_speech: PartOfSpeech,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tense: Option<Tense>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mood: Option<Mood>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub voice: Option<Voice>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case: Option<Case>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub person: Option<Person>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<Comparison>,
+}
+
+impl From<GreekWordParsing> for ParsingComponents {
+    fn from(parsing: GreekWordParsing) -> Self {
+        Self {
+            part_of_speech: parsing.part_of_speech(),
+            tense: parsing.tense(),
+            mood: parsing.mood(),
+            voice: parsing.voice(),
+            case: parsing.case(),
+            gender: parsing.gender(),
+            person: parsing.person(),
+            number: parsing.number(),
+            comparison: parsing.comparison(),
+        }
+    }
+}
 
+impl TryFrom<ParsingComponents> for GreekWordParsing {
+    type Error = String;
+
+    fn try_from(components: ParsingComponents) -> Result<Self, Self::Error> {
+        let codes = [
+            Some(components.part_of_speech.code()),
+            components.tense.map(|c| c.code()),
+            components.mood.map(|c| c.code()),
+            components.voice.map(|c| c.code()),
+            components.case.map(|c| c.code()),
+            components.gender.map(|c| c.code()),
+            components.person.map(|c| c.code()),
+            components.number.map(|c| c.code()),
+            components.comparison.map(|c| c.code()),
+        ];
+        let parsing = Self::from_codes(codes).map_err(|error| error.to_string())?;
+        match Component::ALL.iter().zip(parsing.codes()).zip(codes).find(|((_, parsed), code)| parsed != code) {
+            Some(((component, _), _)) => Err(format!("{component} is not allowed in a {}", components.part_of_speech.code_name())),
+            None => Ok(parsing),
+        }
+    }
+}
 
+/// `#[serde(with = "as_code")]` serializes a parsing as its code, like `"V-AIA-3S"`, instead of its [`ParsingComponents`]
+pub mod as_code {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
 
+    use super::GreekWordParsing;
 
+    pub fn serialize<S: Serializer>(parsing: &GreekWordParsing, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&parsing.to_code())
+    }
 
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<GreekWordParsing, D::Error> {
+        GreekWordParsing::parse(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
 
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
 
+    use crate::greek::query::ParsingQuery;
 
+    use super::*;
 
+    const ALL_CODES: &[&str] = &["N-NFS", "N-GFS", "N-GMS", "N-NMS", "V-AIA-3S", "Art-AMS", "N-AMS", "Conj", "Art-AMP", "PPro-GM3S", "N-AMP", "Prep", "Art-GFS", "Art-GMS", "Art-AFS", "N-AFS", "RelPro-GFS", "V-AIP-3S", "Art-NMS", "V-PPM/P-NMS", "Adj-NFP", "Art-NFP", "N-NFP", "Adv", "Art-NFS", "V-IIA-3S", "V-APP-GFS", "Art-DMS", "N-DMS", "PPro-AM3P", "V-ANA", "V-PPA-NFS", "N-DFS", "Adj-GNS", "N-GNS", "PPro-GF3S", "V-PPA-NMS", "Adj-NMS", "PPro-AF3S", "V-APP-GMS", "DPro-ANP", "V-AMA-2S", "PPro-DM3S", "N-ANS", "N-VMS", "V-ASP-2S", "PPro-G2S", "Art-NNS", "V-APP-NNS", "PPro-DF3S", "V-PIA-3S", "V-FIM-3S", "V-FIA-2S", "Art-ANS", "PPro-NM3S", "V-FIA-3S", "Art-GFP", "PPro-GM3P", "N-GFP", "Adj-NNS", "DPro-NNS", "V-RIA-3S", "V-ASP-3S", "V-PPA-GMS", "V-FIA-3P", "RelPro-NNS", "V-PPM/P-NNS", "PPro-G1P", "V-APP-NMS", "RelPro-GMS", "N-DFP", "N-NMP", "V-AIM-3P", "N-ANP", "V-PPA-NMP", "Art-GMP", "Adj-GMP", "V-AIA-1P", "Art-DFS", "V-APA-NMS", "Adj-NFS", "Adj-AMP", "V-IIM/P-3S", "V-PIM/P-3S", "Art-NMP", "V-AIA-3P", "V-RIM/P-3S", "PPro-N2S", "N-VFS", "V-PIA-2S", "Adj-NFS-S", "Art-DMP", "N-DMP", "RelPro-NMS", "PPro-G1S", "V-PPM/P-GMS", "V-APP-NMP", "V-AMA-2P", "Art-GNS", "V-ASA-2P", "PPro-D1S", "PPro-N1S", "V-ASA-1S", "V-APA-NMP", "V-AIP-3P", "RelPro-AMS", "N-NNS", "Adj-AFS", "Adj-GFS", "V-APA-GMP", "V-PMA-2S", "Prtcl", "PPro-D2S", "V-PNA", "PPro-AN3S", "V-AIA-1S", "N-GMP", "Adj-DNP", "Art-DNP", "N-DNP", "Adj-GMS", "Art-ANP", "V-ANP", "V-PIA-3P", "V-APA-GMS", "V-PMM/P-2S", "V-RIA-3P", "V-PPM/P-AFS", "V-FIP-3S", "Art-DFP", "DPro-DFP", "Adj-DFS", "V-PMA-2P", "DPro-NMS", "Adj-AFP", "N-AFP", "Art-AFP", "PPro-AM3S", "N-NNP", "V-PPM-NMP", "V-IIM/P-3P", "V-PPM/P-AMP", "PPro-DM3P", "N-VNP", "IPro-NMS", "PPro-D2P", "V-PPA-GFS", "Adj-AMS", "RefPro-DM3P", "V-PIA-1P", "V-PIA-1S", "DPro-GMP", "Art-GNP", "N-GNP", "V-PPA-NNS", "PPro-A2P", "N-DNS", "Adj-NMS-C", "Adj-DNS", "V-PIM/P-2S", "PPro-A1S", "PPro-D1P", "V-PPA-ANS", "V-PPM/P-ANS", "RelPro-DMS", "DPro-NMP", "V-ASM-3P", "Adj-DMS", "V-PPM/P-DNS", "PPro-AM2S", "PPro-A2S", "V-ASA-2S", "Adj-ANS", "PPro-GF3P", "Adj-ANP", "V-FIA-1S", "V-IIA-3P", "V-PPM/P-DMP", "V-AIM-3S", "V-PPM/P-AMS", "V-PPA-AMP", "V-M-2P", "Art-DNS", "Adj-DFP", "Adj-NMP", "PPro-NM3P", "V-FIP-3P", "V-FIM-3P", "V-RPM/P-NMP", "V-PIA-2P", "V-ASA-3P", "V-PPM/P-NMP", "PPro-G2P", "V-PMM/P-2P", "PPro-N2P", "IPro-DNS", "V-PNM/P", "V-PPM/P-NFS", "Adj-DMP", "V-AMA-3S", "Heb", "V-ASA-3S", "Adj-NNP", "V-ASM-3S", "Adj-GFP-S", "DPro-GFP", "Adj-NMS-S", "Adj-ANS-C", "V-AIA-2P", "Adj-VMS", "V-PSA-2S", "IPro-ANS", "Adv-S", "V-AMP-2S", "V-FIP-2S", "V-RPM/P-AFS", "V-PMA-3S", "DPro-GNP", "V-PPA-DMS", "V-PPA-AMS", "V-ANM", "V-PPA-GMP", "V-ASM-2P", "IPro-AMS", "IntPrtcl", "V-FIM-2P", "V-ASP-3P", "V-PSA-3S", "V-PSM/P-2P", "V-RPA-NMP", "V-PSM/P-2S", "V-AMM-2S", "V-ASP-2P", "RelPro-GNP", "Art-VMS", "V-AMP-3S", "PPro-N1P", "PPro-A1P", "V-PSA-2P", "IPro-NNS", "V-PIM/P-2P", "DPro-ANS", "Adj-NNS-C", "PPro-AN3P", "PPro-GN3P", "Adj-VMP", "V-ASA-1P", "V-ASM-1P", "Art-NNP", "Adj-GNP", "DPro-NNP", "RefPro-GF3S", "RelPro-DNS", "V-FIP-2P", "PPro-DM2S", "V-FIA-2P", "V-RIA-2P", "V-PPA-DMP", "RelPro-ANP", "V-PSA-3P", "V-RPM/P-NFS", "RelPro-NMP", "V-PIM/P-3P", "DPro-DFS", "Art-VMP", "V-PPM/P-VMP", "DPro-AMP", "V-LIM/P-3S", "RelPro-ANS", "PPro-AM1S", "DPro-DMS", "DPro-AFS", "V-AIA-2S", "V-PPA-AFS", "V-APM-GFS", "RefPro-GM3P", "V-APA-DMS", "V-PIM-1P", "DPro-GFS", "V-PPM/P-GMP", "V-RPM/P-AMS", "N-VNS", "I", "IPro-NMP", "V-RPA-NMS", "V-RSA-2P", "V-APA-AMS", "PPro-GN3S", "V-APA-NFS", "RefPro-DF3S", "V-ASM-1S", "V-FIP-1S", "DPro-NFS", "V-PIM/P-1S", "V-APP-GNS", "V-PPA-NNP", "V-AMP-2P", "V-APM-NMS", "V-RPA-ANP", "RelPro-AFS", "V-AMM-2P", "V-RPM/P-NNS", "V-PMM-2P", "V-RPM/P-NFP", "V-FIM-1S", "Adj-AMS-C", "V-PPM/P-DNP", "RelPro-NNP", "V-AIM-2P", "RelPro-DFP", "Adj-NFP-S", "V-APM-NFP", "V-FIM-2S", "V-PIM-1S", "V-PSM/P-3S", "V-PPA-VMP", "V-RPM/P-VMP", "V-LIA-2P", "V-IIM-3P", "V-APP-NFS", "RefPro-AM3S", "IPro-DMS", "V-APA-NNS", "RefPro-GN3S", "Adj-ANP-C", "V-APA-NNP", "DPro-GMS", "Adj-NNP-C", "V-LIA-3P", "IPro-NFS", "V-LIA-3S", "DPro-DMP", "V-RPM/P-ANS", "RefPro-DM3S", "V-RPM/P-ANP", "V-RPM/P-DMS", "DPro-AMS", "V-APP-DFS", "V-APA-DFS", "DPro-AFP", "V-APM-DNP", "V-PIM-3P", "N-VMP", "V-RIA-2S", "V-PPM/P-NNP", "V-AIP-1S", "V-PPA-GNP", "IPro-AMP", "IPro-AFP", "V-AMM-3S", "V-RPA-GMP", "Adj-VFS", "V-RPM/P-VFS", "V-AIP-1P", "DPro-DNS", "IPro-GMP", "PPro-DN3S", "V-RPM/P-DNP", "V-RPM/P-NNP", "RelPro-GNS", "PPro-AN1S", "V-APM-GMS", "V-APM-ANP", "DPro-GNS", "RelPro-DMP", "RefPro-AM3P", "PPro-DN3P", "V-PPA-ANP", "V-M-2S", "V-RPA-AMP", "V-APA-DMP", "PPro-AN2S", "PPro-DN1P", "V-PIM-2P", "V-PIM/P-1P", "PPro-NN1S", "V-RPM/P-NMS", "Adj-DNP-S", "V-AIM-2S", "IPro-DFS", "V-RIA-1P", "V-AIP-2P", "Adj-AMP-C", "V-PPA-DNS", "V-RPM/P-AMP", "V-RPM/P-DMP", "V-RIA-1S", "RelPro-AMP", "V-RPM-AMS", "IPro-GMS", "V-APP-ANS", "V-RPM/P-GMP", "V-PPM/P-DMS", "V-IIM-1P", "Art-VFS", "V-PPA-VFS", "PPro-GF2S", "RecPro-AMP", "V-RPA-ANS", "V-PMA-3P", "V-PPA-DFP", "RelPro-NFS", "DPro-NFP", "V-PPA-NFP", "RelPro-DFS", "V-PPA-DNP", "RelPro-NFP", "V-APA-NFP", "RefPro-GF3P", "RefPro-DF3P", "V-PPM/P-GFP", "V-IIA-2S", "V-LIA-2S", "V-AIM-1S", "RecPro-GMP", "V-IIM-1S", "Adj-GMP-S", "PPro-NFS", "V-PSA-1S", "V-PSA-1P", "Adj-GFP", "V-IIM/P-1S", "V-IIM-2S", "V-RPA-GMS", "V-RPM/P-GMS", "IPro-ANP", "V-PPA-VMS", "V-FIA-1P", "V-FPA-NMS", "V-APM/P-ANP", "Adv-C", "V-PPM/P-NFP", "Adj-NFS-C", "V-PPA-DFS", "V-APP-NFP", "PPro-DF3P", "V-RPM-NMS", "V-PPM/P-AFP", "V-PNM", "PPro-NM2P", "RefPro-AF3S", "V-RMM/P-2S", "V-RNM/P", "Adj-GMS-S", "Art-VNS", "Adj-VNS", "V-RPA-NNS", "V-RPA-AMS", "PPro-AM2P", "V-APA-AFS", "V-IIM-3S", "V-RPA-NFS", "V-APM-GNS", "V-APA-GFS", "IPro-GFP", "PPro-AM1P", "V-RPA-AFS", "V-APM-NMP", "V-IIM/P-2P", "V-IIA-1P", "RecPro-DMP", "PPro-NF3S", "V-AOA-3S", "V-PPA-GNS", "IPro-NFP", "V-PPM/P-ANP", "V-PPM-NMS", "V-PPM-AMS", "V-RPA-DMP", "RefPro-AF3P", "PPro-AF3P", "V-APM-DMP", "V-RPM/P-GNP", "V-RPA-DMS", "Adj-VMS-S", "RelPro-GMP", "V-AIP-2S", "V-POA-3S", "V-PPM/P-DFS", "V-AOM-3S", "V-APP-AMP", "RelPro-DNP", "RefPro-GM3S", "V-RPM/P-DFS", "V-APP-GNP", "V-IIA-2P", "V-APP-GFP", "V-APA-AMP", "IPro-GFS", "V-RIM/P-3P", "V-AOA-3P", "PPro-NF2P", "PPro-AN2P", "V-PIM-3S", "V-PSM/P-3P", "V-APP-AMS", "V-IIA-1S", "V-RNA", "IPro-AFS", "PPro-NN3S", "V-APA-GNS", "Adj-GNP-C", "Adj-AFP-C", "V-PMM-2S", "Adj-ANS-S", "Adj", "V-RIM/P-2S", "V-ASP-1P", "V-ASP-1S", "PPro-NN1P", "PPro-NN2P", "Adj-NMP-C", "Adj-DNS-S", "DPro-DNP", "V-AMA-3P", "V-APP-ANP", "V-PSM-3S", "V-APM-AMS", "RelPro-GFP", "V-PPM-GMS", "V-PMM/P-3P", "V-PPM/P-GNP", "V-PPM-GNP", "PPro-AF1S", "V-PMM/P-3S", "V-FIM/P-2P", "V-ASM-2S", "IPro-GNS", "PPro-NN2S", "V-FPM-ANS", "V-APM-AMP", "V-APM-AFS", "N-VFP", "V-APP-GMP", "V-APM-ANS", "V-RPA-NFP", "V-PPA-GFP", "V-APM-GFP", "V-RPA-GNP", "V-AIM-1P", "PPro-GM2S", "V-LIA-1S", "V-RIM/P-1S", "Indec", "PPro-NF1S", "V-PPM/P-GNS", "V-PSM/P-1S", "PPro-AF2S", "PPro-GM1S", "PPro-NN3P", "V-APA-ANP", "V-PPM-AFS", "V-PSM/P-1P", "V-FIM-1P", "PPro-NM1S", "V-RIM/P-2P", "Adj-GMP-C", "PPro-DM2P", "PPro-DM1S", "V-RIP-1P", "V-LIM-3P", "IPro-NNP", "PPro-AN1P", "PPro-GN1P", "PPro-AF1P", "PPro-DF1S", "Adj-AFS-C", "PPro-NM2S", "V-RPM/P-GFP", "V-APM/P-GFS", "V-FNA", "V-PPM/P-GFS", "PPro-DF1P", "Adj-DMP-C", "V-RPA-DNS", "V-RPA-GFS", "PPro-DF2S", "V-PPM-DMS", "V-POM/P-1S", "V-PPM-AMP", "V-IIM/P-2S", "V-PPM-NFP", "V-FNM", "V-RPM/P-DFP", "V-PNP", "V-RMM/P-2P", "V-APA-DFP", "V-RPM/P-AFP", "Adj-ANP-S", "V-PPM-GMP", "V-APA-AFP", "V-FPA-ANP", "V-IIM/P-1P", "V-APM-GMP", "V-RPM/P-VMS", "V-APA-ANS", "Adj-DMS-S", "Adj-NFP-C", "V-POA-3P", "V-RIM-2S", "V-POM/P-3S", "Adj-AFS-S", "PPro-GF1P", "V-AOM-1S", "V-LIM-3S", "V-APP-DMS", "V-POM/P-3P", "PPro-GF2P", "IPro-GNP", "V-PPM-ANS", "V-PPP-DMP", "V-PPM/P-VMS", "PPro-DN1S", "V-FIP-1P", "V-RPA-NNP", "Adj-DMS-C", "PPro-GF1S", "V-AMP-3P", "V-APP-AFS", "V-PPP-NMP", "V-PMP-3S", "Adj-VNP", "Art-VNP", "V-PPA-AFP", "V-RPM/P-GNS", "V-PPM-DMP", "V-RSA-1P", "Adj-GNP-S", "V-PIP-3S", "V-PSM-1S", "V-IIP-3P", "V-ANM/P", "V-RSA-1S", "V-PI-3S", "PPro-AF2P", "V-PPM-GFS", "Adj-DFS-C", "V-RPM/P-GFS", "V-RPM/P-DNS", "V-RIM/P-1P", "V-PIP-1S", "V-PPP-GMS", "V-RIM-3S", "IPro-DMP", "V-PPM-NFS", "RecPro-DNP", "PPro-DF2P", "V-RMA-2P", "Art-VFP", "V-PPM-NNS", "V-PMP-2P", "V-AOP-3S", "RelPro-AFP", "V-PSM-2S", "V-PPM/P-DFP", "V-RSA-2S", "PPro-DM1P", "V-PPP-GMP", "PPro-NM1P", "V-APP-DNP", "Adj-GFS-C", "V-FPP-GNP", "Adj-GMS-C", "Adj-DFP-C", "V-APM-GNP", "V-APP-NNP", "V-APM-NFS", "V-IIP-1P", "V-FPA-NMP", "Adj-GNS-S", "V-PPM-GFP", "V-AMM-3P", "V-PPM-GNS", "V-APP-DNS", "Adj-DNS-C", "V-POA-2P", "V-APM/P-NMS", "Adj-VMP-C", "V-APM-NNS", "PPro-NF1P", "V-PSM-1P", "RefPro-AN3P", "RefPro-GN3P", "Adj-DFS-S", "N", "V-RPM-NMP"];
 
+    #[test]
+    fn all_codes() {
+        let errors = ALL_CODES.iter().filter_map(|input| {
+            let result = GreekWordParsing::parse(input);
+            result.is_err().then(|| format!("Code: \"{}\"\nReason: \"{}\"", input, result.unwrap_err()))
+        }).collect_vec();
 
+        if errors.len() > 0 {
+            panic!("{}\nTotal errors: {}", errors.join("\n\n"), errors.len());
+        }
+    }
 
+    #[test]
+    fn round_trip() -> Result<(), GreekParseError> {
+        for code in ALL_CODES {
+            assert_eq!(GreekWordParsing::parse(code)?.to_code(), *code);
+        }
+        Ok(())
+    }
 
+    #[test]
+    fn normalize() -> Result<(), GreekParseError> {
+        assert_eq!(GreekWordParsing::parse("v-apm/p-anp")?.to_code(), "V-APM/P-ANP");
+        assert_eq!(GreekWordParsing::parse("ppro-gm3s")?.to_string(), "PPro-GM3S");
+        assert_eq!("adj-nfs-s".parse::<GreekWordParsing>()?.to_string(), "Adj-NFS-S");
+        Ok(())
+    }
 
+    #[test]
+    fn errors() {
+        let error = |code: &str| GreekWordParsing::parse(code).unwrap_err();
+        assert_eq!(error("N-XYZ"), GreekParseError::Invalid { input: "N-XYZ".to_string(), segment: 1, offset: 0, expected: Component::Case, found: "X".to_string() });
+        assert_eq!(error("Art-AF"), GreekParseError::Missing { input: "Art-AF".to_string(), segment: 1, offset: 2, expected: Component::Number });
+        assert_eq!(error("Art"), GreekParseError::Missing { input: "Art".to_string(), segment: 1, offset: 0, expected: Component::Case });
+        assert_eq!(error("Q-ANP"), GreekParseError::Invalid { input: "Q-ANP".to_string(), segment: 0, offset: 0, expected: Component::PartOfSpeech, found: "Q".to_string() });
+        assert_eq!(error("Adj-ANP-X"), GreekParseError::Invalid { input: "Adj-ANP-X".to_string(), segment: 2, offset: 0, expected: Component::Comparison, found: "X".to_string() });
+        assert_eq!(error("PPro-G3"), GreekParseError::Missing { input: "PPro-G3".to_string(), segment: 1, offset: 2, expected: Component::Number });
+        assert_eq!(error("PPro-G3").to_string(), "Number is required at segment 1, offset 2 of 'PPro-G3'");
+    }
 
+    #[test]
+    fn trailing() {
+        let unknown = |code: &str, segment: usize, offset: usize, found: &str| {
+            assert_eq!(
+                GreekWordParsing::parse(code).unwrap_err(),
+                GreekParseError::Unknown { input: code.to_string(), segment, offset, found: found.to_string() },
+                "{code}"
+            );
+        };
+        unknown("N-NFSX", 1, 3, "X");
+        unknown("V-AIA-3SX", 2, 2, "X");
+        unknown("N-NFS-ABC", 2, 0, "ABC");
+        unknown("V-AIA-3S-Q", 3, 0, "Q");
+        unknown("Adj-NFSX-C", 1, 3, "X");
+        unknown("Conj-XYZ", 1, 0, "XYZ");
+        unknown("Prep-G", 1, 0, "G");
+        unknown("Heb-N", 1, 0, "N");
+        unknown("Aram-", 1, 0, "");
+
+        for mode in [ParsingMode::Lenient, ParsingMode::Strict] {
+            assert_eq!(
+                GreekWordParsing::parse_with("V-AIZ-3S", mode).unwrap_err(),
+                GreekParseError::Invalid { input: "V-AIZ-3S".to_string(), segment: 1, offset: 2, expected: Component::Voice, found: "Z".to_string() }
+            );
+        }
+    }
 
+    #[test]
+    fn query() -> Result<(), GreekParseError> {
+        let parsings = ALL_CODES.iter().map(|code| GreekWordParsing::parse(code)).collect::<Result<Vec<_>, _>>()?;
+        let select = |query: &str| -> Result<Vec<String>, GreekParseError> {
+            let query = ParsingQuery::parse(query)?;
+            Ok(parsings.iter().filter(|parsing| query.matches(parsing)).map(|parsing| parsing.to_code()).sorted().collect())
+        };
+        let expected = |filter: fn(&str) -> bool| ALL_CODES.iter().filter(|code| filter(code)).map(|code| code.to_string()).sorted().collect_vec();
 
+        for parsing in &parsings {
+            assert!(ParsingQuery::parse(&parsing.to_code())?.matches(parsing), "{parsing}");
+        }
 
+        let genitive_aorist_passive_participles = expected(|code| code.starts_with("V-APP-G"));
+        assert!(!genitive_aorist_passive_participles.is_empty());
+        assert_eq!(select("V-A?P-G??")?, genitive_aorist_passive_participles);
+        assert_eq!(select("pos=verb tense=aorist voice=passive mood=participle case=genitive")?, genitive_aorist_passive_participles);
 
-#[cfg(test)]
-mod test {
-    use itertools::Itertools;
+        let second_plural_present_imperatives = expected(|code| code.starts_with("V-PM") && code.ends_with("-2P"));
+        assert!(!second_plural_present_imperatives.is_empty());
+        assert_eq!(select("V-PM?-2P")?, second_plural_present_imperatives);
+        assert_eq!(select("tense=present mood=imperative person=2 number=plural")?, second_plural_present_imperatives);
 
-    use super::*;
+        assert_eq!(select("degree=comparative")?, expected(|code| code.ends_with("-C")));
+        assert_eq!(select("pos=ppro|refpro person=1|2")?, expected(|code| (code.starts_with("PPro-") || code.starts_with("RefPro-")) && (code.contains('1') || code.contains('2'))));
+        Ok(())
+    }
 
     #[test]
-    fn all_codes() {
-        let codes = &["N-NFS", "N-GFS", "N-GMS", "N-NMS", "V-AIA-3S", "Art-AMS", "N-AMS", "Conj", "Art-AMP", "PPro-GM3S", "N-AMP", "Prep", "Art-GFS", "Art-GMS", "Art-AFS", "N-AFS", "RelPro-GFS", "V-AIP-3S", "Art-NMS", "V-PPM/P-NMS", "Adj-NFP", "Art-NFP", "N-NFP", "Adv", "Art-NFS", "V-IIA-3S", "V-APP-GFS", "Art-DMS", "N-DMS", "PPro-AM3P", "V-ANA", "V-PPA-NFS", "N-DFS", "Adj-GNS", "N-GNS", "PPro-GF3S", "V-PPA-NMS", "Adj-NMS", "PPro-AF3S", "V-APP-GMS", "DPro-ANP", "V-AMA-2S", "PPro-DM3S", "N-ANS", "N-VMS", "V-ASP-2S", "PPro-G2S", "Art-NNS", "V-APP-NNS", "PPro-DF3S", "V-PIA-3S", "V-FIM-3S", "V-FIA-2S", "Art-ANS", "PPro-NM3S", "V-FIA-3S", "Art-GFP", "PPro-GM3P", "N-GFP", "Adj-NNS", "DPro-NNS", "V-RIA-3S", "V-ASP-3S", "V-PPA-GMS", "V-FIA-3P", "RelPro-NNS", "V-PPM/P-NNS", "PPro-G1P", "V-APP-NMS", "RelPro-GMS", "N-DFP", "N-NMP", "V-AIM-3P", "N-ANP", "V-PPA-NMP", "Art-GMP", "Adj-GMP", "V-AIA-1P", "Art-DFS", "V-APA-NMS", "Adj-NFS", "Adj-AMP", "V-IIM/P-3S", "V-PIM/P-3S", "Art-NMP", "V-AIA-3P", "V-RIM/P-3S", "PPro-N2S", "N-VFS", "V-PIA-2S", "Adj-NFS-S", "Art-DMP", "N-DMP", "RelPro-NMS", "PPro-G1S", "V-PPM/P-GMS", "V-APP-NMP", "V-AMA-2P", "Art-GNS", "V-ASA-2P", "PPro-D1S", "PPro-N1S", "V-ASA-1S", "V-APA-NMP", "V-AIP-3P", "RelPro-AMS", "N-NNS", "Adj-AFS", "Adj-GFS", "V-APA-GMP", "V-PMA-2S", "Prtcl", "PPro-D2S", "V-PNA", "PPro-AN3S", "V-AIA-1S", "N-GMP", "Adj-DNP", "Art-DNP", "N-DNP", "Adj-GMS", "Art-ANP", "V-ANP", "V-PIA-3P", "V-APA-GMS", "V-PMM/P-2S", "V-RIA-3P", "V-PPM/P-AFS", "V-FIP-3S", "Art-DFP", "DPro-DFP", "Adj-DFS", "V-PMA-2P", "DPro-NMS", "Adj-AFP", "N-AFP", "Art-AFP", "PPro-AM3S", "N-NNP", "V-PPM-NMP", "V-IIM/P-3P", "V-PPM/P-AMP", "PPro-DM3P", "N-VNP", "IPro-NMS", "PPro-D2P", "V-PPA-GFS", "Adj-AMS", "RefPro-DM3P", "V-PIA-1P", "V-PIA-1S", "DPro-GMP", "Art-GNP", "N-GNP", "V-PPA-NNS", "PPro-A2P", "N-DNS", "Adj-NMS-C", "Adj-DNS", "V-PIM/P-2S", "PPro-A1S", "PPro-D1P", "V-PPA-ANS", "V-PPM/P-ANS", "RelPro-DMS", "DPro-NMP", "V-ASM-3P", "Adj-DMS", "V-PPM/P-DNS", "PPro-AM2S", "PPro-A2S", "V-ASA-2S", "Adj-ANS", "PPro-GF3P", "Adj-ANP", "V-FIA-1S", "V-IIA-3P", "V-PPM/P-DMP", "V-AIM-3S", "V-PPM/P-AMS", "V-PPA-AMP", "V-M-2P", "Art-DNS", "Adj-DFP", "Adj-NMP", "PPro-NM3P", "V-FIP-3P", "V-FIM-3P", "V-RPM/P-NMP", "V-PIA-2P", "V-ASA-3P", "V-PPM/P-NMP", "PPro-G2P", "V-PMM/P-2P", "PPro-N2P", "IPro-DNS", "V-PNM/P", "V-PPM/P-NFS", "Adj-DMP", "V-AMA-3S", "Heb", "V-ASA-3S", "Adj-NNP", "V-ASM-3S", "Adj-GFP-S", "DPro-GFP", "Adj-NMS-S", "Adj-ANS-C", "V-AIA-2P", "Adj-VMS", "V-PSA-2S", "IPro-ANS", "Adv-S", "V-AMP-2S", "V-FIP-2S", "V-RPM/P-AFS", "V-PMA-3S", "DPro-GNP", "V-PPA-DMS", "V-PPA-AMS", "V-ANM", "V-PPA-GMP", "V-ASM-2P", "IPro-AMS", "IntPrtcl", "V-FIM-2P", "V-ASP-3P", "V-PSA-3S", "V-PSM/P-2P", "V-RPA-NMP", "V-PSM/P-2S", "V-AMM-2S", "V-ASP-2P", "RelPro-GNP", "Art-VMS", "V-AMP-3S", "PPro-N1P", "PPro-A1P", "V-PSA-2P", "IPro-NNS", "V-PIM/P-2P", "DPro-ANS", "Adj-NNS-C", "PPro-AN3P", "PPro-GN3P", "Adj-VMP", "V-ASA-1P", "V-ASM-1P", "Art-NNP", "Adj-GNP", "DPro-NNP", "RefPro-GF3S", "RelPro-DNS", "V-FIP-2P", "PPro-DM2S", "V-FIA-2P", "V-RIA-2P", "V-PPA-DMP", "RelPro-ANP", "V-PSA-3P", "V-RPM/P-NFS", "RelPro-NMP", "V-PIM/P-3P", "DPro-DFS", "Art-VMP", "V-PPM/P-VMP", "DPro-AMP", "V-LIM/P-3S", "RelPro-ANS", "PPro-AM1S", "DPro-DMS", "DPro-AFS", "V-AIA-2S", "V-PPA-AFS", "V-APM-GFS", "RefPro-GM3P", "V-APA-DMS", "V-PIM-1P", "DPro-GFS", "V-PPM/P-GMP", "V-RPM/P-AMS", "N-VNS", "I", "IPro-NMP", "V-RPA-NMS", "V-RSA-2P", "V-APA-AMS", "PPro-GN3S", "V-APA-NFS", "RefPro-DF3S", "V-ASM-1S", "V-FIP-1S", "DPro-NFS", "V-PIM/P-1S", "V-APP-GNS", "V-PPA-NNP", "V-AMP-2P", "V-APM-NMS", "V-RPA-ANP", "RelPro-AFS", "V-AMM-2P", "V-RPM/P-NNS", "V-PMM-2P", "V-RPM/P-NFP", "V-FIM-1S", "Adj-AMS-C", "V-PPM/P-DNP", "RelPro-NNP", "V-AIM-2P", "RelPro-DFP", "Adj-NFP-S", "V-APM-NFP", "V-FIM-2S", "V-PIM-1S", "V-PSM/P-3S", "V-PPA-VMP", "V-RPM/P-VMP", "V-LIA-2P", "V-IIM-3P", "V-APP-NFS", "RefPro-AM3S", "IPro-DMS", "V-APA-NNS", "RefPro-GN3S", "Adj-ANP-C", "V-APA-NNP", "DPro-GMS", "Adj-NNP-C", "V-LIA-3P", "IPro-NFS", "V-LIA-3S", "DPro-DMP", "V-RPM/P-ANS", "RefPro-DM3S", "V-RPM/P-ANP", "V-RPM/P-DMS", "DPro-AMS", "V-APP-DFS", "V-APA-DFS", "DPro-AFP", "V-APM-DNP", "V-PIM-3P", "N-VMP", "V-RIA-2S", "V-PPM/P-NNP", "V-AIP-1S", "V-PPA-GNP", "IPro-AMP", "IPro-AFP", "V-AMM-3S", "V-RPA-GMP", "Adj-VFS", "V-RPM/P-VFS", "V-AIP-1P", "DPro-DNS", "IPro-GMP", "PPro-DN3S", "V-RPM/P-DNP", "V-RPM/P-NNP", "RelPro-GNS", "PPro-AN1S", "V-APM-GMS", "V-APM-ANP", "DPro-GNS", "RelPro-DMP", "RefPro-AM3P", "PPro-DN3P", "V-PPA-ANP", "V-M-2S", "V-RPA-AMP", "V-APA-DMP", "PPro-AN2S", "PPro-DN1P", "V-PIM-2P", "V-PIM/P-1P", "PPro-NN1S", "V-RPM/P-NMS", "Adj-DNP-S", "V-AIM-2S", "IPro-DFS", "V-RIA-1P", "V-AIP-2P", "Adj-AMP-C", "V-PPA-DNS", "V-RPM/P-AMP", "V-RPM/P-DMP", "V-RIA-1S", "RelPro-AMP", "V-RPM-AMS", "IPro-GMS", "V-APP-ANS", "V-RPM/P-GMP", "V-PPM/P-DMS", "V-IIM-1P", "Art-VFS", "V-PPA-VFS", "PPro-GF2S", "RecPro-AMP", "V-RPA-ANS", "V-PMA-3P", "V-PPA-DFP", "RelPro-NFS", "DPro-NFP", "V-PPA-NFP", "RelPro-DFS", "V-PPA-DNP", "RelPro-NFP", "V-APA-NFP", "RefPro-GF3P", "RefPro-DF3P", "V-PPM/P-GFP", "V-IIA-2S", "V-LIA-2S", "V-AIM-1S", "RecPro-GMP", "V-IIM-1S", "Adj-GMP-S", "PPro-NFS", "V-PSA-1S", "V-PSA-1P", "Adj-GFP", "V-IIM/P-1S", "V-IIM-2S", "V-RPA-GMS", "V-RPM/P-GMS", "IPro-ANP", "V-PPA-VMS", "V-FIA-1P", "V-FPA-NMS", "V-APM/P-ANP", "Adv-C", "V-PPM/P-NFP", "Adj-NFS-C", "V-PPA-DFS", "V-APP-NFP", "PPro-DF3P", "V-RPM-NMS", "V-PPM/P-AFP", "V-PNM", "PPro-NM2P", "RefPro-AF3S", "V-RMM/P-2S", "V-RNM/P", "Adj-GMS-S", "Art-VNS", "Adj-VNS", "V-RPA-NNS", "V-RPA-AMS", "PPro-AM2P", "V-APA-AFS", "V-IIM-3S", "V-RPA-NFS", "V-APM-GNS", "V-APA-GFS", "IPro-GFP", "PPro-AM1P", "V-RPA-AFS", "V-APM-NMP", "V-IIM/P-2P", "V-IIA-1P", "RecPro-DMP", "PPro-NF3S", "V-AOA-3S", "V-PPA-GNS", "IPro-NFP", "V-PPM/P-ANP", "V-PPM-NMS", "V-PPM-AMS", "V-RPA-DMP", "RefPro-AF3P", "PPro-AF3P", "V-APM-DMP", "V-RPM/P-GNP", "V-RPA-DMS", "Adj-VMS-S", "RelPro-GMP", "V-AIP-2S", "V-POA-3S", "V-PPM/P-DFS", "V-AOM-3S", "V-APP-AMP", "RelPro-DNP", "RefPro-GM3S", "V-RPM/P-DFS", "V-APP-GNP", "V-IIA-2P", "V-APP-GFP", "V-APA-AMP", "IPro-GFS", "V-RIM/P-3P", "V-AOA-3P", "PPro-NF2P", "PPro-AN2P", "V-PIM-3S", "V-PSM/P-3P", "V-APP-AMS", "V-IIA-1S", "V-RNA", "IPro-AFS", "PPro-NN3S", "V-APA-GNS", "Adj-GNP-C", "Adj-AFP-C", "V-PMM-2S", "Adj-ANS-S", "Adj", "V-RIM/P-2S", "V-ASP-1P", "V-ASP-1S", "PPro-NN1P", "PPro-NN2P", "Adj-NMP-C", "Adj-DNS-S", "DPro-DNP", "V-AMA-3P", "V-APP-ANP", "V-PSM-3S", "V-APM-AMS", "RelPro-GFP", "V-PPM-GMS", "V-PMM/P-3P", "V-PPM/P-GNP", "V-PPM-GNP", "PPro-AF1S", "V-PMM/P-3S", "V-FIM/P-2P", "V-ASM-2S", "IPro-GNS", "PPro-NN2S", "V-FPM-ANS", "V-APM-AMP", "V-APM-AFS", "N-VFP", "V-APP-GMP", "V-APM-ANS", "V-RPA-NFP", "V-PPA-GFP", "V-APM-GFP", "V-RPA-GNP", "V-AIM-1P", "PPro-GM2S", "V-LIA-1S", "V-RIM/P-1S", "Indec", "PPro-NF1S", "V-PPM/P-GNS", "V-PSM/P-1S", "PPro-AF2S", "PPro-GM1S", "PPro-NN3P", "V-APA-ANP", "V-PPM-AFS", "V-PSM/P-1P", "V-FIM-1P", "PPro-NM1S", "V-RIM/P-2P", "Adj-GMP-C", "PPro-DM2P", "PPro-DM1S", "V-RIP-1P", "V-LIM-3P", "IPro-NNP", "PPro-AN1P", "PPro-GN1P", "PPro-AF1P", "PPro-DF1S", "Adj-AFS-C", "PPro-NM2S", "V-RPM/P-GFP", "V-APM/P-GFS", "V-FNA", "V-PPM/P-GFS", "PPro-DF1P", "Adj-DMP-C", "V-RPA-DNS", "V-RPA-GFS", "PPro-DF2S", "V-PPM-DMS", "V-POM/P-1S", "V-PPM-AMP", "V-IIM/P-2S", "V-PPM-NFP", "V-FNM", "V-RPM/P-DFP", "V-PNP", "V-RMM/P-2P", "V-APA-DFP", "V-RPM/P-AFP", "Adj-ANP-S", "V-PPM-GMP", "V-APA-AFP", "V-FPA-ANP", "V-IIM/P-1P", "V-APM-GMP", "V-RPM/P-VMS", "V-APA-ANS", "Adj-DMS-S", "Adj-NFP-C", "V-POA-3P", "V-RIM-2S", "V-POM/P-3S", "Adj-AFS-S", "PPro-GF1P", "V-AOM-1S", "V-LIM-3S", "V-APP-DMS", "V-POM/P-3P", "PPro-GF2P", "IPro-GNP", "V-PPM-ANS", "V-PPP-DMP", "V-PPM/P-VMS", "PPro-DN1S", "V-FIP-1P", "V-RPA-NNP", "Adj-DMS-C", "PPro-GF1S", "V-AMP-3P", "V-APP-AFS", "V-PPP-NMP", "V-PMP-3S", "Adj-VNP", "Art-VNP", "V-PPA-AFP", "V-RPM/P-GNS", "V-PPM-DMP", "V-RSA-1P", "Adj-GNP-S", "V-PIP-3S", "V-PSM-1S", "V-IIP-3P", "V-ANM/P", "V-RSA-1S", "V-PI-3S", "PPro-AF2P", "V-PPM-GFS", "Adj-DFS-C", "V-RPM/P-GFS", "V-RPM/P-DNS", "V-RIM/P-1P", "V-PIP-1S", "V-PPP-GMS", "V-RIM-3S", "IPro-DMP", "V-PPM-NFS", "RecPro-DNP", "PPro-DF2P", "V-RMA-2P", "Art-VFP", "V-PPM-NNS", "V-PMP-2P", "V-AOP-3S", "RelPro-AFP", "V-PSM-2S", "V-PPM/P-DFP", "V-RSA-2S", "PPro-DM1P", "V-PPP-GMP", "PPro-NM1P", "V-APP-DNP", "Adj-GFS-C", "V-FPP-GNP", "Adj-GMS-C", "Adj-DFP-C", "V-APM-GNP", "V-APP-NNP", "V-APM-NFS", "V-IIP-1P", "V-FPA-NMP", "Adj-GNS-S", "V-PPM-GFP", "V-AMM-3P", "V-PPM-GNS", "V-APP-DNS", "Adj-DNS-C", "V-POA-2P", "V-APM/P-NMS", "Adj-VMP-C", "V-APM-NNS", "PPro-NF1P", "V-PSM-1P", "RefPro-AN3P", "RefPro-GN3P", "Adj-DFS-S", "N", "V-RPM-NMP"];
+    fn serde() -> Result<(), Box<dyn std::error::Error>> {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Word {
+            #[serde(with = "as_code")]
+            parsing: GreekWordParsing,
+        }
 
-        let errors = codes.iter().filter_map(|input| {
-            let result = GreekWordParsing::parse(input);
-            result.is_err().then(|| format!("Code: \"{}\"\nReason: \"{}\"", input, result.unwrap_err()))
-        }).collect_vec();
+        for code in ALL_CODES.iter().chain(&["V-2AID-3S", "V-PIX-3S"]) {
+            let parsing = GreekWordParsing::parse(code)?;
+            let json = serde_json::to_string(&parsing)?;
+            assert_eq!(serde_json::from_str::<GreekWordParsing>(&json)?, parsing, "{code} {json}");
 
-        if errors.len() > 0 {
-            panic!("{}\nTotal errors: {}", errors.join("\n\n"), errors.len());
+            let word = Word { parsing };
+            assert_eq!(serde_json::to_string(&word)?, format!(r#"{{"parsing":"{code}"}}"#));
+            assert_eq!(serde_json::from_str::<Word>(&serde_json::to_string(&word)?)?, word);
         }
+
+        let parsing = GreekWordParsing::parse("V-AIA-3S")?;
+        assert_eq!(
+            serde_json::to_string(&parsing)?,
+            r#"{"part_of_speech":"Verb","tense":"Aorist","mood":"Indicative","voice":"Active","person":"3rd Person","number":"Singular"}"#
+        );
+        assert_eq!(serde_json::from_str::<GreekWordParsing>(r#"{"part_of_speech":"V","tense":"A","mood":"I","voice":"A","person":"3","number":"S"}"#)?, parsing);
+        assert_eq!(serde_json::to_string(&GreekWordParsing::parse("Conj")?)?, r#"{"part_of_speech":"Conjunction"}"#);
+
+        let error = |json: &str| serde_json::from_str::<GreekWordParsing>(json).unwrap_err().to_string();
+        assert_eq!(error(r#"{"part_of_speech":"Noun","tense":"Aorist","case":"Nominative","gender":"Feminine","number":"Singular"}"#), "Tense is not allowed in a Noun");
+        assert_eq!(error(r#"{"part_of_speech":"Article","case":"Nominative"}"#), "Gender is required at segment 1, offset 1 of 'Art-N'");
+        assert!(error(r#"{"part_of_speech":"Noun","aspect":"Perfective"}"#).starts_with("unknown field `aspect`"));
+        assert!(serde_json::from_str::<Word>(r#"{"parsing":"N-XYZ"}"#).is_err());
+        Ok(())
     }
 }
-
@@ -1 +1,1009 @@
 // https://biblehub.com/hebrewparse.htm
+
+use std::{fmt::Display, str::FromStr};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::greek::components::{ComponentCode, part_of_speech::PartOfSpeech, serde_code_name};
+
+/**
+Qal, Nifal, Piel, Pual, Hifil, Hofal, Hitpael and the rarer stems, including the Aramaic ones
+
+The codes use the Bible Hub spelling (`Nifal`, `Hifil`), but the academic spelling (`Niphal`, `Hiphil`) is also accepted
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stem {
+    /// Qal
+    Qal,
+    /// QalPass - Qal passive
+    QalPassive,
+    /// Nifal
+    Niphal,
+    /// Piel
+    Piel,
+    /// Pual
+    Pual,
+    /// Hifil
+    Hiphil,
+    /// Hofal
+    Hophal,
+    /// Hitpael
+    Hithpael,
+    /// Hitpolel
+    Hithpolel,
+    /// Hitpalpel
+    Hithpalpel,
+    /// Hotpaal
+    Hothpaal,
+    /// Nitpael
+    Nithpael,
+    /// Polel
+    Polel,
+    /// Polal
+    Polal,
+    /// Pilpel
+    Pilpel,
+    /// Poel
+    Poel,
+    /// Poal
+    Poal,
+    /// Palel
+    Palel,
+    /// Pulal
+    Pulal,
+    /// Hishtafel
+    Hishtaphel,
+    /// Tifil
+    Tiphil,
+    /// Peal (Aramaic)
+    Peal,
+    /// Peil (Aramaic)
+    Peil,
+    /// Pael (Aramaic)
+    Pael,
+    /// Hafel (Aramaic)
+    Haphel,
+    /// Afel (Aramaic)
+    Aphel,
+    /// Shafel (Aramaic)
+    Shaphel,
+    /// Hitpeel (Aramaic)
+    Hithpeel,
+    /// Hitpaal (Aramaic)
+    Hithpaal,
+    /// Ishtafal (Aramaic)
+    Ishtaphal,
+    /// Hishtafal (Aramaic)
+    Hishtaphal,
+}
+
+impl Stem {
+    pub const ALL: [Self; 31] = [
+        Self::Qal, Self::QalPassive, Self::Niphal, Self::Piel, Self::Pual, Self::Hiphil, Self::Hophal, Self::Hithpael,
+        Self::Hithpolel, Self::Hithpalpel, Self::Hothpaal, Self::Nithpael, Self::Polel, Self::Polal, Self::Pilpel, Self::Poel,
+        Self::Poal, Self::Palel, Self::Pulal, Self::Hishtaphel, Self::Tiphil,
+        Self::Peal, Self::Peil, Self::Pael, Self::Haphel, Self::Aphel, Self::Shaphel, Self::Hithpeel, Self::Hithpaal, Self::Ishtaphal, Self::Hishtaphal,
+    ];
+}
+
+impl FromStr for Stem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let academic = match s.to_lowercase().as_str() {
+            "niphal" => Some(Self::Niphal),
+            "hiphil" => Some(Self::Hiphil),
+            "hophal" => Some(Self::Hophal),
+            "hithpael" => Some(Self::Hithpael),
+            "hithpolel" => Some(Self::Hithpolel),
+            "hithpalpel" => Some(Self::Hithpalpel),
+            "hothpaal" => Some(Self::Hothpaal),
+            "nithpael" => Some(Self::Nithpael),
+            "hishtaphel" => Some(Self::Hishtaphel),
+            "tiphil" => Some(Self::Tiphil),
+            "haphel" => Some(Self::Haphel),
+            "aphel" => Some(Self::Aphel),
+            "shaphel" => Some(Self::Shaphel),
+            "hithpeel" => Some(Self::Hithpeel),
+            "hithpaal" => Some(Self::Hithpaal),
+            "ishtaphal" => Some(Self::Ishtaphal),
+            "hishtaphal" => Some(Self::Hishtaphal),
+            _ => None,
+        };
+        academic
+            .or_else(|| Self::ALL.into_iter().find(|stem| stem.code().eq_ignore_ascii_case(s)))
+            .ok_or_else(|| format!("Invalid Stem - '{s}'"))
+    }
+}
+
+impl Display for Stem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl ComponentCode for Stem {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Qal => "Qal",
+            Self::QalPassive => "QalPass",
+            Self::Niphal => "Nifal",
+            Self::Piel => "Piel",
+            Self::Pual => "Pual",
+            Self::Hiphil => "Hifil",
+            Self::Hophal => "Hofal",
+            Self::Hithpael => "Hitpael",
+            Self::Hithpolel => "Hitpolel",
+            Self::Hithpalpel => "Hitpalpel",
+            Self::Hothpaal => "Hotpaal",
+            Self::Nithpael => "Nitpael",
+            Self::Polel => "Polel",
+            Self::Polal => "Polal",
+            Self::Pilpel => "Pilpel",
+            Self::Poel => "Poel",
+            Self::Poal => "Poal",
+            Self::Palel => "Palel",
+            Self::Pulal => "Pulal",
+            Self::Hishtaphel => "Hishtafel",
+            Self::Tiphil => "Tifil",
+            Self::Peal => "Peal",
+            Self::Peil => "Peil",
+            Self::Pael => "Pael",
+            Self::Haphel => "Hafel",
+            Self::Aphel => "Afel",
+            Self::Shaphel => "Shafel",
+            Self::Hithpeel => "Hitpeel",
+            Self::Hithpaal => "Hitpaal",
+            Self::Ishtaphal => "Ishtafal",
+            Self::Hishtaphal => "Hishtafal",
+        }
+    }
+
+    fn code_name(&self) -> &'static str {
+        match self {
+            Self::Qal => "Qal",
+            Self::QalPassive => "Qal Passive",
+            Self::Niphal => "Niphal",
+            Self::Piel => "Piel",
+            Self::Pual => "Pual",
+            Self::Hiphil => "Hiphil",
+            Self::Hophal => "Hophal",
+            Self::Hithpael => "Hithpael",
+            Self::Hithpolel => "Hithpolel",
+            Self::Hithpalpel => "Hithpalpel",
+            Self::Hothpaal => "Hothpaal",
+            Self::Nithpael => "Nithpael",
+            Self::Polel => "Polel",
+            Self::Polal => "Polal",
+            Self::Pilpel => "Pilpel",
+            Self::Poel => "Poel",
+            Self::Poal => "Poal",
+            Self::Palel => "Palel",
+            Self::Pulal => "Pulal",
+            Self::Hishtaphel => "Hishtaphel",
+            Self::Tiphil => "Tiphil",
+            Self::Peal => "Peal",
+            Self::Peil => "Peil",
+            Self::Pael => "Pael",
+            Self::Haphel => "Haphel",
+            Self::Aphel => "Aphel",
+            Self::Shaphel => "Shaphel",
+            Self::Hithpeel => "Hithpeel",
+            Self::Hithpaal => "Hithpaal",
+            Self::Ishtaphal => "Ishtaphal",
+            Self::Hishtaphal => "Hishtaphal",
+        }
+    }
+}
+
+/**
+Perf - Perfect
+Imperf - Imperfect
+ConsecImperf - Consecutive imperfect
+ConjPerf - Conjunctive perfect
+Imperf.Jus - Imperfect Jussive
+Imperf.Cohort - Imperfect Cohortative
+Imp - Imperative
+Inf - Infinitive construct
+InfAbs - Infinitive absolute
+Prtcpl - Participle
+QalPassPrtcpl - Qal passive participle
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Conjugation {
+    /// Perf - Perfect
+    Perfect,
+    /// Imperf - Imperfect
+    Imperfect,
+    /// ConsecImperf - Consecutive imperfect (wayyiqtol)
+    ConsecutiveImperfect,
+    /// ConjPerf - Conjunctive perfect (weqatal)
+    ConjunctivePerfect,
+    /// Imperf.Jus - Imperfect Jussive
+    Jussive,
+    /// Imperf.Cohort - Imperfect Cohortative
+    Cohortative,
+    /// Imp - Imperative
+    Imperative,
+    /// Inf - Infinitive construct
+    InfinitiveConstruct,
+    /// InfAbs - Infinitive absolute
+    InfinitiveAbsolute,
+    /// Prtcpl - Participle
+    Participle,
+    /// QalPassPrtcpl - Qal passive participle
+    PassiveParticiple,
+}
+
+impl Conjugation {
+    pub const ALL: [Self; 11] = [
+        Self::Perfect, Self::Imperfect, Self::ConsecutiveImperfect, Self::ConjunctivePerfect, Self::Jussive, Self::Cohortative,
+        Self::Imperative, Self::InfinitiveConstruct, Self::InfinitiveAbsolute, Self::Participle, Self::PassiveParticiple,
+    ];
+}
+
+impl FromStr for Conjugation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL.into_iter()
+            .find(|conjugation| conjugation.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid Conjugation - '{s}'"))
+    }
+}
+
+impl Display for Conjugation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl ComponentCode for Conjugation {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Perfect => "Perf",
+            Self::Imperfect => "Imperf",
+            Self::ConsecutiveImperfect => "ConsecImperf",
+            Self::ConjunctivePerfect => "ConjPerf",
+            Self::Jussive => "Imperf.Jus",
+            Self::Cohortative => "Imperf.Cohort",
+            Self::Imperative => "Imp",
+            Self::InfinitiveConstruct => "Inf",
+            Self::InfinitiveAbsolute => "InfAbs",
+            Self::Participle => "Prtcpl",
+            Self::PassiveParticiple => "QalPassPrtcpl",
+        }
+    }
+
+    fn code_name(&self) -> &'static str {
+        match self {
+            Self::Perfect => "Perfect",
+            Self::Imperfect => "Imperfect",
+            Self::ConsecutiveImperfect => "Consecutive Imperfect",
+            Self::ConjunctivePerfect => "Conjunctive Perfect",
+            Self::Jussive => "Imperfect Jussive",
+            Self::Cohortative => "Imperfect Cohortative",
+            Self::Imperative => "Imperative",
+            Self::InfinitiveConstruct => "Infinitive Construct",
+            Self::InfinitiveAbsolute => "Infinitive Absolute",
+            Self::Participle => "Participle",
+            Self::PassiveParticiple => "Qal Passive Participle",
+        }
+    }
+}
+
+/**
+1 - first person
+2 - second person
+3 - third person
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Person {
+    /// 1 - first person
+    First,
+    /// 2 - second person
+    Second,
+    /// 3 - third person
+    Third,
+}
+
+impl Person {
+    pub const ALL: [Self; 3] = [Self::First, Self::Second, Self::Third];
+}
+
+impl FromStr for Person {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => Self::First,
+            "2" => Self::Second,
+            "3" => Self::Third,
+            _ => Err(format!("Invalid Person - '{s}'"))?
+        })
+    }
+}
+
+impl Display for Person {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl ComponentCode for Person {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::First => "1",
+            Self::Second => "2",
+            Self::Third => "3",
+        }
+    }
+
+    fn code_name(&self) -> &'static str {
+        match self {
+            Self::First => "1st Person",
+            Self::Second => "2nd Person",
+            Self::Third => "3rd Person",
+        }
+    }
+}
+
+/**
+m - masculine
+f - feminine
+c - common
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Gender {
+    /// m - masculine
+    Masculine,
+    /// f - feminine
+    Feminine,
+    /// c - common
+    Common,
+}
+
+impl Gender {
+    pub const ALL: [Self; 3] = [Self::Masculine, Self::Feminine, Self::Common];
+}
+
+impl FromStr for Gender {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "m" => Self::Masculine,
+            "f" => Self::Feminine,
+            "c" => Self::Common,
+            _ => Err(format!("Invalid Gender - '{s}'"))?
+        })
+    }
+}
+
+impl Display for Gender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl ComponentCode for Gender {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Masculine => "m",
+            Self::Feminine => "f",
+            Self::Common => "c",
+        }
+    }
+
+    fn code_name(&self) -> &'static str {
+        match self {
+            Self::Masculine => "Masculine",
+            Self::Feminine => "Feminine",
+            Self::Common => "Common",
+        }
+    }
+}
+
+/**
+s - singular
+p - plural
+d - dual
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Number {
+    /// s - singular
+    Singular,
+    /// p - plural
+    Plural,
+    /// d - dual
+    Dual,
+}
+
+impl Number {
+    pub const ALL: [Self; 3] = [Self::Singular, Self::Plural, Self::Dual];
+}
+
+impl FromStr for Number {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "s" => Self::Singular,
+            "p" => Self::Plural,
+            "d" => Self::Dual,
+            _ => Err(format!("Invalid Number - '{s}'"))?
+        })
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl ComponentCode for Number {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Singular => "s",
+            Self::Plural => "p",
+            Self::Dual => "d",
+        }
+    }
+
+    fn code_name(&self) -> &'static str {
+        match self {
+            Self::Singular => "Singular",
+            Self::Plural => "Plural",
+            Self::Dual => "Dual",
+        }
+    }
+}
+
+/**
+c - construct
+d - determinate (Aramaic)
+
+The absolute state is not marked
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum State {
+    /// c - construct
+    Construct,
+    /// d - determinate (Aramaic)
+    Determinate,
+}
+
+impl State {
+    pub const ALL: [Self; 2] = [Self::Construct, Self::Determinate];
+}
+
+impl FromStr for State {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "c" => Self::Construct,
+            "d" => Self::Determinate,
+            _ => Err(format!("Invalid State - '{s}'"))?
+        })
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl ComponentCode for State {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Construct => "c",
+            Self::Determinate => "d",
+        }
+    }
+
+    fn code_name(&self) -> &'static str {
+        match self {
+            Self::Construct => "Construct",
+            Self::Determinate => "Determinate",
+        }
+    }
+}
+
+/// Person, gender, number and state, always in that order and each optional
+/// - `3ms`: third person masculine singular
+/// - `mpc`: masculine plural construct
+/// - `fsd`: feminine singular determinate
+/// - `cs`: common singular
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Inflection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub person: Option<Person>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<Number>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+}
+
+impl FromStr for Inflection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.split_inclusive(|_| true).peekable();
+
+        let person: Option<Person> = chars.peek().and_then(|c| c.parse().ok());
+        if person.is_some() { chars.next(); }
+        let gender: Option<Gender> = chars.peek().and_then(|c| c.parse().ok());
+        if gender.is_some() { chars.next(); }
+        let number: Option<Number> = chars.peek().and_then(|c| c.parse().ok());
+        if number.is_some() { chars.next(); }
+        let state: Option<State> = chars.peek().and_then(|c| c.parse().ok());
+        if state.is_some() { chars.next(); }
+
+        let inflection = Self { person, gender, number, state };
+        if chars.next().is_some() || inflection == Self::default() {
+            Err(format!("Invalid Inflection - '{s}'"))?
+        }
+        Ok(inflection)
+    }
+}
+
+impl Display for Inflection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let codes = [
+            self.person.map(|person| person.code()),
+            self.gender.map(|gender| gender.code()),
+            self.number.map(|number| number.code()),
+            self.state.map(|state| state.code()),
+        ];
+        write!(f, "{}", codes.into_iter().flatten().join(""))
+    }
+}
+
+/// `b`, `k`, `l` and `m` are prefixed to the word they govern
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InseparablePreposition {
+    /// b - in
+    B,
+    /// k - like
+    K,
+    /// l - to
+    L,
+    /// m - from
+    M,
+}
+
+impl InseparablePreposition {
+    pub const ALL: [Self; 4] = [Self::B, Self::K, Self::L, Self::M];
+}
+
+impl FromStr for InseparablePreposition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "b" => Self::B,
+            "k" => Self::K,
+            "l" => Self::L,
+            "m" => Self::M,
+            _ => Err(format!("Invalid Preposition - '{s}'"))?
+        })
+    }
+}
+
+impl Display for InseparablePreposition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl ComponentCode for InseparablePreposition {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::B => "b",
+            Self::K => "k",
+            Self::L => "l",
+            Self::M => "m",
+        }
+    }
+
+    fn code_name(&self) -> &'static str {
+        match self {
+            Self::B => "Bet",
+            Self::K => "Kaf",
+            Self::L => "Lamed",
+            Self::M => "Mem",
+        }
+    }
+}
+
+/// `Pro-r`, `Pro-i` or `Pro-3ms`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PronounParsing {
+    /// Pro-r - relative
+    Relative,
+    /// Pro-i - interrogative
+    Interrogative,
+    /// Pro-3ms - personal, or demonstrative when there is no person
+    Inflected(Inflection),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct VerbParsing {
+    pub stem: Stem,
+    pub conjugation: Conjugation,
+    /// Infinitives have no inflection
+    pub inflection: Option<Inflection>,
+}
+
+/// A single morpheme of a Hebrew or Aramaic word
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HebrewMorpheme {
+    /// Adj-ms
+    Adjective(Inflection),
+    /// Adv
+    Adverb,
+    /// Adv-NegPrt
+    NegativeParticle,
+    /// Art
+    Article,
+    /// Conj, Conj-w
+    Conjunction {
+        waw: bool,
+    },
+    /// DirObjM
+    DirectObjectMarker,
+    /// Interjection
+    Interjection,
+    /// Interrog
+    Interrogative,
+    /// N-ms, N-proper-ms, N-proper
+    Noun {
+        proper: bool,
+        inflection: Option<Inflection>,
+    },
+    /// Number-ms
+    Number(Inflection),
+    /// Prep, Prep-b
+    Preposition(Option<InseparablePreposition>),
+    /// Pro-3ms, Pro-r
+    Pronoun(PronounParsing),
+    /// V-Qal-Perf-3ms
+    Verb(VerbParsing),
+}
+
+impl HebrewMorpheme {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut segments = input.split("-");
+        let part_of_speech = segments.next().ok_or_else(|| "Part of Speech not included".to_string())?;
+        let rest = segments.clone().join("-");
+        let rest = (!rest.is_empty()).then_some(rest.as_str());
+
+        let inflection = || -> Result<Inflection, String> {
+            rest.ok_or_else(|| format!("{part_of_speech}: Inflection is required"))?.parse()
+        };
+        let nothing = |morpheme: Self| match rest {
+            Some(rest) => Err(format!("{part_of_speech}: Unexpected '{rest}'")),
+            None => Ok(morpheme),
+        };
+
+        match part_of_speech {
+            "Adj" => Ok(Self::Adjective(inflection()?)),
+            "Adv" => match rest {
+                Some("NegPrt") => Ok(Self::NegativeParticle),
+                _ => nothing(Self::Adverb),
+            },
+            "Art" => nothing(Self::Article),
+            "Conj" => match rest {
+                Some("w") => Ok(Self::Conjunction { waw: true }),
+                _ => nothing(Self::Conjunction { waw: false }),
+            },
+            "DirObjM" => nothing(Self::DirectObjectMarker),
+            "Interjection" => nothing(Self::Interjection),
+            "Interrog" => nothing(Self::Interrogative),
+            "N" => {
+                let proper = segments.clone().next() == Some("proper");
+                if proper { segments.next(); }
+                let inflection = segments.next().map(|inflection| inflection.parse()).transpose()?;
+                if let Some(extra) = segments.next() {
+                    Err(format!("Noun: Unexpected '{extra}'"))?
+                }
+                if !proper && inflection.is_none() {
+                    Err("Noun: Inflection is required".to_string())?
+                }
+                Ok(Self::Noun { proper, inflection })
+            },
+            "Number" => Ok(Self::Number(inflection()?)),
+            "Prep" => Ok(Self::Preposition(rest.map(|letter| letter.parse()).transpose()?)),
+            "Pro" => Ok(Self::Pronoun(match rest {
+                Some("r") => PronounParsing::Relative,
+                Some("i") => PronounParsing::Interrogative,
+                _ => PronounParsing::Inflected(inflection()?),
+            })),
+            "V" => {
+                let stem: Stem = segments.next().ok_or_else(|| "Verb: Stem is required".to_string())?.parse()?;
+                let conjugation: Conjugation = segments.next().ok_or_else(|| "Verb: Conjugation is required".to_string())?.parse()?;
+                let inflection = segments.next().map(|inflection| inflection.parse()).transpose()?;
+                if let Some(extra) = segments.next() {
+                    Err(format!("Verb: Unexpected '{extra}'"))?
+                }
+                Ok(Self::Verb(VerbParsing { stem, conjugation, inflection }))
+            },
+            _ => Err(format!("Invalid Part of Speech - '{part_of_speech}'")),
+        }
+    }
+
+    /// The inverse of [`HebrewMorpheme::parse`]
+    pub fn code(&self) -> String {
+        match self {
+            Self::Adjective(inflection) => format!("Adj-{inflection}"),
+            Self::Adverb => "Adv".to_string(),
+            Self::NegativeParticle => "Adv-NegPrt".to_string(),
+            Self::Article => "Art".to_string(),
+            Self::Conjunction { waw: true } => "Conj-w".to_string(),
+            Self::Conjunction { waw: false } => "Conj".to_string(),
+            Self::DirectObjectMarker => "DirObjM".to_string(),
+            Self::Interjection => "Interjection".to_string(),
+            Self::Interrogative => "Interrog".to_string(),
+            Self::Noun { proper, inflection } => {
+                let mut code = "N".to_string();
+                if *proper { code.push_str("-proper"); }
+                if let Some(inflection) = inflection { code.push_str(&format!("-{inflection}")); }
+                code
+            },
+            Self::Number(inflection) => format!("Number-{inflection}"),
+            Self::Preposition(None) => "Prep".to_string(),
+            Self::Preposition(Some(letter)) => format!("Prep-{letter}"),
+            Self::Pronoun(PronounParsing::Relative) => "Pro-r".to_string(),
+            Self::Pronoun(PronounParsing::Interrogative) => "Pro-i".to_string(),
+            Self::Pronoun(PronounParsing::Inflected(inflection)) => format!("Pro-{inflection}"),
+            Self::Verb(VerbParsing { stem, conjugation, inflection: None }) => format!("V-{stem}-{conjugation}"),
+            Self::Verb(VerbParsing { stem, conjugation, inflection: Some(inflection) }) => format!("V-{stem}-{conjugation}-{inflection}"),
+        }
+    }
+
+    /// Whether this can be prefixed to another word: `Conj-w`, `Art`, `Prep-b`, `Interrog` or `Pro-r`
+    pub fn is_prefix(&self) -> bool {
+        matches!(self,
+            Self::Conjunction { waw: true }
+            | Self::Article
+            | Self::Preposition(_)
+            | Self::Interrogative
+            | Self::Pronoun(PronounParsing::Relative)
+        )
+    }
+
+    /// The name of the part of speech, in the vocabulary of [`PartOfSpeech::code_name`] so Hebrew and Greek words can be grouped together
+    /// - `"Noun"` for `N-proper-ms`, `"Verb"` for `V-Qal-Perf-3ms`
+    /// - `"Personal / Possessive Pronoun"` for `Pro-3ms`, `"Demonstrative Pronoun"` for `Pro-ms`
+    /// - The direct object marker and numbers have no Greek part of speech: `"Direct Object Marker"` and `"Number"`
+    pub fn part_of_speech(&self) -> &'static str {
+        let part_of_speech = match self {
+            Self::Adjective(_) => PartOfSpeech::Adjective,
+            Self::Adverb => PartOfSpeech::Adverb,
+            Self::NegativeParticle => PartOfSpeech::Particle,
+            Self::Article => PartOfSpeech::Article,
+            Self::Conjunction { .. } => PartOfSpeech::Conjunction,
+            Self::DirectObjectMarker => return "Direct Object Marker",
+            Self::Interjection => PartOfSpeech::Interjection,
+            Self::Interrogative => PartOfSpeech::InterrogativeParticle,
+            Self::Noun { .. } => PartOfSpeech::Noun,
+            Self::Number(_) => return "Number",
+            Self::Preposition(_) => PartOfSpeech::Preposition,
+            Self::Pronoun(PronounParsing::Relative) => PartOfSpeech::RelativePronoun,
+            Self::Pronoun(PronounParsing::Interrogative) => PartOfSpeech::InterrogativeIndefinitePronoun,
+            Self::Pronoun(PronounParsing::Inflected(Inflection { person: Some(_), .. })) => PartOfSpeech::PersonalPossessivePronoun,
+            Self::Pronoun(PronounParsing::Inflected(_)) => PartOfSpeech::DemonstrativePronoun,
+            Self::Verb(_) => PartOfSpeech::Verb,
+        };
+        part_of_speech.code_name()
+    }
+
+    pub fn inflection(&self) -> Option<Inflection> {
+        match self {
+            Self::Adjective(inflection) | Self::Number(inflection) => Some(*inflection),
+            Self::Noun { inflection, .. } => *inflection,
+            Self::Pronoun(PronounParsing::Inflected(inflection)) => Some(*inflection),
+            Self::Verb(verb) => verb.inflection,
+            _ => None,
+        }
+    }
+}
+
+impl Display for HebrewMorpheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Format: `Prefixes | Word | Suffix`
+/// - `Prep-b | N-fs`
+/// - `Conj-w, Art | N-fs`
+/// - `Conj-w | V-Qal-ConsecImperf-3ms`
+/// - `Prep | 3ms`
+/// - `N-mpc | 1cs`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct HebrewWordParsing {
+    /// Conjunctions, the article, prepositions, the interrogative and the relative prefixed to the word
+    pub prefixes: Vec<HebrewMorpheme>,
+    pub word: HebrewMorpheme,
+    /// Pronominal suffix
+    pub suffix: Option<Inflection>,
+}
+
+impl HebrewWordParsing {
+    pub fn parse(input: &str) -> Result<HebrewWordParsing, String> {
+        let morphemes = input.split(" | ").flat_map(|group| group.split(", ")).map(str::trim).collect_vec();
+        let (suffix, morphemes) = match morphemes.split_last() {
+            Some((last, rest)) if !rest.is_empty() && last.parse::<Inflection>().is_ok() => (Some(last.parse()?), rest),
+            _ => (None, &morphemes[..]),
+        };
+        let (word, prefixes) = morphemes.split_last().ok_or_else(|| "Part of Speech not included".to_string())?;
+
+        let word = HebrewMorpheme::parse(word)?;
+        let prefixes = prefixes.iter().map(|prefix| HebrewMorpheme::parse(prefix)).collect::<Result<Vec<_>, _>>()?;
+        if let Some(prefix) = prefixes.iter().find(|prefix| !prefix.is_prefix()) {
+            Err(format!("Invalid Prefix - '{prefix}'"))?
+        }
+
+        Ok(Self { prefixes, word, suffix })
+    }
+
+    /// The inverse of [`HebrewWordParsing::parse`]
+    pub fn code(&self) -> String {
+        let prefixes = (!self.prefixes.is_empty()).then(|| self.prefixes.iter().join(", "));
+        let suffix = self.suffix.map(|suffix| suffix.to_string());
+        prefixes.into_iter()
+            .chain([self.word.code()])
+            .chain(suffix)
+            .join(" | ")
+    }
+
+    pub fn stem(&self) -> Option<Stem> {
+        match self.word {
+            HebrewMorpheme::Verb(verb) => Some(verb.stem),
+            _ => None,
+        }
+    }
+
+    pub fn conjugation(&self) -> Option<Conjugation> {
+        match self.word {
+            HebrewMorpheme::Verb(verb) => Some(verb.conjugation),
+            _ => None,
+        }
+    }
+
+    pub fn person(&self) -> Option<Person> {
+        self.word.inflection()?.person
+    }
+
+    pub fn gender(&self) -> Option<Gender> {
+        self.word.inflection()?.gender
+    }
+
+    pub fn number(&self) -> Option<Number> {
+        self.word.inflection()?.number
+    }
+
+    pub fn state(&self) -> Option<State> {
+        self.word.inflection()?.state
+    }
+}
+
+serde_code_name!(Stem, Conjugation, Person, Gender, Number, State, InseparablePreposition);
+
+impl FromStr for HebrewWordParsing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Display for HebrewWordParsing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+
+    const ALL_CODES: &[&str] = &["Prep-b | N-fs", "N-mp", "V-Qal-Perf-3ms", "DirObjM", "Art | N-mp", "Conj-w | DirObjM", "Art | N-fs", "Conj-w, Art | N-fs", "V-Qal-Perf-3fs", "N-ms", "Conj-w | N-ms", "Conj-w | N-ms", "Prep | N-mpc", "Conj-w | N-fs", "V-Piel-Prtcpl-fs", "Prep | N-mpc", "Art | N-mp", "Conj-w | V-Qal-ConsecImperf-3ms", "N-proper-ms", "V-Qal-Imperf.Jus-3ms", "Conj-w | V-Qal-ConsecImperf-3ms", "Prep | 3ms", "N-proper-fs", "Conj-w | N-proper-ms", "N-msc", "N-fsd", "Prep-l | N-ms", "Prep-k | N-msc | 1cp", "Prep-m | N-proper-ms", "Conj-w, Prep-l | N-fp", "Prep-b, Art | N-ms", "N-mpc | 3ms", "N-fsc | 2ms", "N-md", "N-mdc | 3fs", "Adj-ms", "Adj-fpc", "Conj-w | Adj-mp", "Number-ms", "Number-fsc", "Number-cs", "Pro-3ms", "Pro-1cs", "Pro-r", "Pro-i", "Pro-ms", "Adv", "Adv-NegPrt", "Conj", "Prep", "Interjection", "Interrog", "Interrog | Adv-NegPrt", "Conj-w, Interrog | Pro-2ms", "Pro-r | Prep", "N-proper", "Prep-b | N-proper", "V-Qal-Imperf-3ms", "V-Qal-Imperf-1cs | 3ms", "V-Qal-Imp-ms", "V-Qal-Imp-mp | 1cs", "V-Qal-Inf", "V-Qal-Inf | 3ms", "Prep-l | V-Qal-Inf", "V-Qal-InfAbs", "V-Qal-Prtcpl-ms", "V-Qal-Prtcpl-mpc", "Art | V-Qal-Prtcpl-ms", "V-Qal-QalPassPrtcpl-ms", "Conj-w | V-Qal-ConjPerf-2ms", "V-Qal-Imperf.Cohort-1cs", "V-Nifal-Perf-3ms", "V-Nifal-Imperf-3ms", "V-Nifal-Prtcpl-ms", "V-Piel-Perf-3ms", "V-Piel-Inf", "V-Pual-Perf-3ms", "V-Pual-Prtcpl-ms", "V-Hifil-Perf-3ms", "Conj-w | V-Hifil-ConsecImperf-3ms", "V-Hifil-Imp-ms", "V-Hofal-Perf-3ms", "V-Hitpael-Imperf-3mp", "Conj-w | V-Hitpael-ConsecImperf-3ms", "V-Hitpolel-Imperf-3ms", "V-Hitpalpel-Perf-3cp", "V-Polel-Perf-3ms", "V-Polal-Perf-3ms", "V-Pilpel-Imperf-3ms", "V-Poel-Prtcpl-ms", "V-Poal-Perf-3ms", "V-Palel-Perf-3ms", "V-Pulal-Perf-3ms", "V-Hotpaal-Perf-3fs", "V-Nitpael-Perf-3cp", "V-Hishtafel-Imperf-3mp", "V-Tifil-Perf-1cs", "V-QalPass-Perf-3ms", "V-Peal-Perf-3ms", "V-Peal-Prtcpl-mp", "V-Peil-Perf-3fs", "V-Pael-Inf", "V-Hafel-Perf-3ms", "V-Afel-Imperf-3ms", "V-Shafel-Perf-3ms", "V-Hitpeel-Imperf-3ms", "V-Hitpaal-Prtcpl-mp", "V-Ishtafal-Perf-3ms", "V-Hishtafal-Prtcpl-ms", "N-mpd", "Adj-msd", "Conj-w | Pro-3mp", "Prep-l | Pro-i"];
+
+    #[test]
+    fn bsb() -> Result<(), String> {
+        let parsing = HebrewWordParsing::parse("Conj-w, Art | N-fs")?;
+        assert_eq!(parsing.prefixes, vec![HebrewMorpheme::Conjunction { waw: true }, HebrewMorpheme::Article]);
+        assert_eq!(parsing.word, HebrewMorpheme::Noun { proper: false, inflection: Some("fs".parse()?) });
+        assert_eq!(parsing.gender(), Some(Gender::Feminine));
+        assert_eq!(parsing.suffix, None);
+
+        let parsing = HebrewWordParsing::parse("Conj-w | V-Qal-ConsecImperf-3ms")?;
+        assert_eq!(parsing.stem(), Some(Stem::Qal));
+        assert_eq!(parsing.conjugation(), Some(Conjugation::ConsecutiveImperfect));
+        assert_eq!(parsing.person(), Some(Person::Third));
+        assert_eq!(parsing.number(), Some(Number::Singular));
+
+        let parsing = HebrewWordParsing::parse("Prep | 3ms")?;
+        assert_eq!(parsing.word, HebrewMorpheme::Preposition(None));
+        assert_eq!(parsing.suffix, Some(Inflection { person: Some(Person::Third), gender: Some(Gender::Masculine), number: Some(Number::Singular), state: None }));
+        assert_eq!(parsing.person(), None);
+
+        assert_eq!(HebrewWordParsing::parse("N-fsd")?.state(), Some(State::Determinate));
+        assert_eq!(HebrewWordParsing::parse("N-msc")?.state(), Some(State::Construct));
+        assert_eq!(HebrewWordParsing::parse("Pro-1cs")?.gender(), Some(Gender::Common));
+        assert_eq!(HebrewWordParsing::parse("V-Hifil-Inf | 3ms")?.word, HebrewMorpheme::Verb(VerbParsing { stem: Stem::Hiphil, conjugation: Conjugation::InfinitiveConstruct, inflection: None }));
+
+        // the academic spelling of a stem is written back with the Bible Hub spelling
+        assert_eq!(HebrewWordParsing::parse("V-Niphal-Perf-3ms")?.code(), "V-Nifal-Perf-3ms");
+
+        assert!(HebrewWordParsing::parse("").is_err());
+        assert!(HebrewWordParsing::parse("V-Qal").is_err());
+        assert!(HebrewWordParsing::parse("V-Qal-Perf-3xs").is_err());
+        assert!(HebrewWordParsing::parse("N").is_err());
+        assert!(HebrewWordParsing::parse("N-fs | Prep").is_err());
+        assert!(HebrewWordParsing::parse("DirObjM-x").is_err());
+        assert!(HebrewWordParsing::parse("Xyz").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn code_names() {
+        assert_eq!(Stem::QalPassive.code_name(), "Qal Passive");
+        assert_eq!(Stem::Hiphil.code_name(), "Hiphil");
+        assert_eq!(Conjugation::ConsecutiveImperfect.code_name(), "Consecutive Imperfect");
+        assert_eq!(Person::First.code_name(), "1st Person");
+        assert_eq!(Gender::Feminine.code_name(), "Feminine");
+        assert_eq!(Number::Dual.code_name(), "Dual");
+        assert_eq!(State::Construct.code_name(), "Construct");
+        assert_eq!(InseparablePreposition::L.code_name(), "Lamed");
+    }
+
+    #[test]
+    fn all_codes() {
+        let errors = ALL_CODES.iter().filter_map(|input| {
+            match HebrewWordParsing::parse(input) {
+                Ok(parsing) if parsing.code() == *input => None,
+                Ok(parsing) => Some(format!("Code: \"{}\"\nWritten back as: \"{}\"", input, parsing.code())),
+                Err(reason) => Some(format!("Code: \"{}\"\nReason: \"{}\"", input, reason)),
+            }
+        }).collect_vec();
+
+        if !errors.is_empty() {
+            panic!("{}\nTotal errors: {}", errors.join("\n\n"), errors.len());
+        }
+    }
+
+    #[test]
+    fn serde() -> Result<(), Box<dyn std::error::Error>> {
+        for code in ALL_CODES {
+            let parsing = HebrewWordParsing::parse(code)?;
+            let json = serde_json::to_string(&parsing)?;
+            assert_eq!(serde_json::from_str::<HebrewWordParsing>(&json)?, parsing, "{code} {json}");
+        }
+
+        assert_eq!(
+            serde_json::to_string(&HebrewWordParsing::parse("Conj-w | V-Qal-ConsecImperf-3ms")?)?,
+            r#"{"prefixes":[{"conjunction":{"waw":true}}],"word":{"verb":{"stem":"Qal","conjugation":"Consecutive Imperfect","inflection":{"person":"3rd Person","gender":"Masculine","number":"Singular"}}},"suffix":null}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&HebrewWordParsing::parse("Prep-b, Art | N-ms")?)?,
+            r#"{"prefixes":[{"preposition":"Bet"},"article"],"word":{"noun":{"proper":false,"inflection":{"gender":"Masculine","number":"Singular"}}},"suffix":null}"#
+        );
+        assert_eq!(serde_json::from_str::<Inflection>(r#"{"person":"1","gender":"common","number":"s"}"#)?, "1cs".parse()?);
+        assert_eq!(serde_json::from_str::<Stem>(r#""Hiphil""#)?, Stem::Hiphil);
+        Ok(())
+    }
+}
@@ -1,8 +1,17 @@
+use std::{collections::BTreeMap, convert::Infallible, fmt::Display, str::FromStr};
+
 use bible_reference_parser::{book_chapter_verse::BookChapterVerse, passage_segments::chapter_verse::ChapterVerse};
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::books::decode_verse_id;
 
 /// Okay, but what about when Greek words are out of order?
 /// Maybe all BSB words are in order
-#[derive(Clone, Debug)]
+///
+/// Serialized as `"omitted"`, `"earlier"`, `"later"` or `{"text":[{"space":" "},{"grammar":"This is the"},{"space":" "},{"word":"record"},{"space":" "}]}`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TranslatedWord {
     /// " [This is the] record ": Text translated into segments
     Text(Vec<TranslatedTextSegment>),
@@ -14,44 +23,291 @@ pub enum TranslatedWord {
     Later,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TranslatedTextSegment {
     /// "record" in " [This is the] record "
     Word(String),
     /// "This is the" in " [This is the] record "
     Grammar(String),
+    /// " " around "[This is the]" and "record" in " [This is the] record "
+    /// - Kept so that the cell can be written back exactly
+    Space(String),
+}
+
+impl TranslatedWord {
+    const OMITTED: &str = " - ";
+    const EARLIER: &str = " . . . ";
+    const LATER: &str = " vvv ";
+
+    /// Splits text into segments
+    /// - A `[` without a matching `]` (or a `]` without a `[`) is kept as part of a [`TranslatedTextSegment::Word`]
+    /// - Brackets inside brackets are kept as part of the [`TranslatedTextSegment::Grammar`]: `"[a [b] c]"` is `Grammar("a [b] c")`
+    fn segments(text: &str) -> Vec<TranslatedTextSegment> {
+        let mut segments = vec![];
+        let mut literal = 0;
+        let mut index = 0;
+        while let Some(open) = text[index..].find('[').map(|open| open + index) {
+            match Self::matching_bracket(&text[open..]).map(|close| close + open) {
+                Some(close) => {
+                    Self::push_literal(&mut segments, &text[literal..open]);
+                    segments.push(TranslatedTextSegment::Grammar(text[open + 1..close].to_string()));
+                    literal = close + 1;
+                    index = close + 1;
+                },
+                None => index = open + 1,
+            }
+        }
+        Self::push_literal(&mut segments, &text[literal..]);
+        segments
+    }
+
+    /// Index of the `]` closing the `[` at the start of `text`
+    fn matching_bracket(text: &str) -> Option<usize> {
+        let mut depth = 0;
+        for (index, c) in text.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                },
+                _ => (),
+            }
+        }
+        None
+    }
+
+    /// `"  and void "` is `[Space("  "), Word("and void"), Space(" ")]`
+    fn push_literal(segments: &mut Vec<TranslatedTextSegment>, text: &str) {
+        let word = text.trim();
+        if word.is_empty() {
+            if !text.is_empty() {
+                segments.push(TranslatedTextSegment::Space(text.to_string()));
+            }
+            return;
+        }
+        let start = text.len() - text.trim_start().len();
+        let end = start + word.len();
+        if start > 0 {
+            segments.push(TranslatedTextSegment::Space(text[..start].to_string()));
+        }
+        segments.push(TranslatedTextSegment::Word(word.to_string()));
+        if end < text.len() {
+            segments.push(TranslatedTextSegment::Space(text[end..].to_string()));
+        }
+    }
+}
+
+impl FromStr for TranslatedWord {
+    type Err = Infallible;
+
+    /// Excel Column: `" BSB version "`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "-" => Self::Omitted,
+            ". . ." => Self::Earlier,
+            "vvv" => Self::Later,
+            _ => Self::Text(Self::segments(s)),
+        })
+    }
+}
+
+impl Display for TranslatedWord {
+    /// The original `" BSB version "` cell
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(segments) => segments.iter().try_for_each(|segment| write!(f, "{}", segment)),
+            Self::Omitted => write!(f, "{}", Self::OMITTED),
+            Self::Earlier => write!(f, "{}", Self::EARLIER),
+            Self::Later => write!(f, "{}", Self::LATER),
+        }
+    }
+}
+
+impl Display for TranslatedTextSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Word(word) => write!(f, "{}", word),
+            Self::Grammar(grammar) => write!(f, "[{}]", grammar),
+            Self::Space(space) => write!(f, "{}", space),
+        }
+    }
 }
 
 /**
 This should be a mix of language, parsing, and strong's number
 */
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Language {
     Hebrew,
     Greek,
     Aramaic,
 }
 
-#[derive(Clone, Debug)]
+impl FromStr for Language {
+    type Err = String;
+
+    /// Excel Column: `"Language"`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Hebrew" => Self::Hebrew,
+            "Greek" => Self::Greek,
+            "Aramaic" => Self::Aramaic,
+            _ => Err(format!("Invalid Language - '{s}'"))?
+        })
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Hebrew => "Hebrew",
+            Self::Greek => "Greek",
+            Self::Aramaic => "Aramaic",
+        })
+    }
+}
+
+impl Language {
+    /// Guesses the language of a word whose `"Language"` cell is invalid, from
+    /// - which Strong's number column is populated
+    /// - the testament of the verse (`book` 1-39 is the Old Testament)
+    /// - the script of the `"WLC / Nestle Base TR RP WH NE NA SBL"` text
+    ///
+    /// Returns [`None`] when there is no evidence or the evidence disagrees
+    /// - Aramaic cannot be told apart from Hebrew this way, so it is inferred as [`Language::Hebrew`]
+    pub fn infer(strongs_hebrew: Option<u32>, strongs_greek: Option<u32>, book: Option<u8>, text: &str) -> Option<Self> {
+        let strongs = [
+            strongs_hebrew.map(|_| Self::Hebrew),
+            strongs_greek.map(|_| Self::Greek),
+        ];
+        let testament = book.map(|book| if book < 40 { Self::Hebrew } else { Self::Greek });
+        let script = text.chars().find_map(|c| match c {
+            '\u{0590}'..='\u{05FF}' | '\u{FB1D}'..='\u{FB4F}' => Some(Self::Hebrew),
+            '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' => Some(Self::Greek),
+            _ => None,
+        });
+
+        strongs.into_iter()
+            .chain([testament, script])
+            .flatten()
+            .dedup()
+            .exactly_one()
+            .ok()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InterlinearVerseEntry {
-    verse: BookChapterVerse,
-    words: Vec<InterlinearWordEntry>,
+    /// Excel Column: `"Verse"` (numeric), see [`Self::verse`]
+    #[serde(deserialize_with = "deserialize_verse_id")]
+    pub verse_id: u32,
+    pub words: Vec<InterlinearWordEntry>,
+}
+
+/// A `verse_id` that [`decode_verse_id`] accepts, so [`InterlinearVerseEntry::verse`] and [`InterlinearWordEntry::verse`] can't fail on a deserialized entry
+fn deserialize_verse_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let verse_id = u32::deserialize(deserializer)?;
+    match decode_verse_id(verse_id) {
+        Some(_) => Ok(verse_id),
+        None => Err(serde::de::Error::custom(format!("Invalid verse id - '{verse_id}'"))),
+    }
+}
+
+/// Which sort column orders the words of a verse
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordOrder {
+    /// Excel Column: `"BSB Sort"`: English reading order
+    Bsb,
+    /// Excel Column: `"Heb Sort"`
+    Hebrew,
+    /// Excel Column: `"Greek Sort"`
+    Greek,
+    /// `"Greek Sort"` for Greek words, otherwise `"Heb Sort"`
+    Original,
+}
+
+impl WordOrder {
+    pub fn key(&self, word: &InterlinearWordEntry) -> u32 {
+        match self {
+            Self::Bsb => word.bsb_sort,
+            Self::Hebrew => word.hebrew_sort,
+            Self::Greek => word.greek_sort,
+            Self::Original => match word.language {
+                Language::Greek => word.greek_sort,
+                Language::Hebrew | Language::Aramaic => word.hebrew_sort,
+            },
+        }
+    }
+}
+
+impl InterlinearVerseEntry {
+    /// Decoded from [`Self::verse_id`]
+    pub fn verse(&self) -> BookChapterVerse {
+        decode_verse_id(self.verse_id).expect("verse ids are checked when converted or deserialized")
+    }
+
+    /// Groups words by their verse, in canonical verse order, with the words of each verse in `order`
+    pub fn group(words: Vec<InterlinearWordEntry>, order: WordOrder) -> Vec<Self> {
+        let mut verses: BTreeMap<u32, Vec<InterlinearWordEntry>> = BTreeMap::new();
+        for word in words {
+            verses.entry(word.verse_id).or_default().push(word);
+        }
+
+        verses.into_values()
+            .map(|words| {
+                let mut verse = Self { verse_id: words[0].verse_id, words };
+                verse.sort_words(order);
+                verse
+            })
+            .collect()
+    }
+
+    pub fn sort_words(&mut self, order: WordOrder) {
+        self.words.sort_by_key(|word| (order.key(word), word.bsb_sort));
+    }
+
+    /// The words in another order without reordering this verse
+    pub fn words_in(&self, order: WordOrder) -> Vec<&InterlinearWordEntry> {
+        self.words.iter()
+            .sorted_by_key(|word| (order.key(word), word.bsb_sort))
+            .collect()
+    }
 }
 
 /// Taken from `BSB Translation Tables - xlsx` at https://berean.bible/downloads.htm
 /// - HTML Markup seems to use `|` instead of `"`
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InterlinearWordEntry {
+    /**
+    Excel Column: `"Heb Sort"`
+    */
+    pub hebrew_sort: u32,
 
     /**
-    Excel Column: `"Verse"`
+    Excel Column: `"Greek Sort"`
+    */
+    pub greek_sort: u32,
+
+    /**
+    Excel Column: `"BSB Sort"`
+    */
+    pub bsb_sort: u32,
+
+    /**
+    Excel Column: `"Verse"` (numeric), see [`Self::verse`]
     */
-    verse: BookChapterVerse,
+    #[serde(deserialize_with = "deserialize_verse_id")]
+    pub verse_id: u32,
 
     /**
     Excel Column: `"Language"`
     */
-    language: Language,
+    pub language: Language,
 
     /**
     Excel Column: `"WLC / Nestle Base TR RP WH NE NA SBL"`
@@ -66,39 +322,39 @@ pub struct InterlinearWordEntry {
     - **NA:** NA27
     - **SBL:** SBLGNT
     */
-    text_1: String,
+    pub text_1: String,
 
     /**
     Excel Column: `"WLC / Nestle Base {TR} ⧼RP⧽ (WH) 〈NE〉 [NA] ‹SBL› [[ECM]]"`
     - It seems to be exactly like column 1, but it is for words not contained in the base text
     */
-    text_2: String,
+    pub text_2: String,
 
     /**
     Excel Column: `"Translit"`
     */
-    transliteration: String,
+    pub transliteration: String,
 
     /**
     Excel Column: `"Parsing"`
     I can probably merge this with Language, because parsing depends on language
     */
-    parsing_code: String,
+    pub parsing_code: String,
 
     /**
     Excel Column: `"Parsing"`
     */
-    parsing: String,
+    pub parsing: String,
 
     /**
     Excel Column: `"Str Heb"`
     */
-    strongs_hebrew: Option<u32>,
+    pub strongs_hebrew: Option<u32>,
 
     /**
     Excel Column: `"Str Grk"`
     */
-    strongs_greek: Option<u32>,
+    pub strongs_greek: Option<u32>,
 
     /**
     Excel Column: `"Hdg"`
@@ -109,7 +365,7 @@ pub struct InterlinearWordEntry {
     ```
     which is really `"The Genealogy of Jesus"`
     */
-    heading: Option<String>,
+    pub heading: Option<String>,
 
     /**
     Excel Column: `"Crossref"`
@@ -121,7 +377,7 @@ pub struct InterlinearWordEntry {
     ```
     which is really `["Ruth 4:18–22", "Luke 3:23–38"]`
     */
-    crossref: Option<Vec<String>>,
+    pub crossref: Option<Vec<String>>,
 
     /**
     Excel Column: `"Par"`
@@ -148,7 +404,7 @@ pub struct InterlinearWordEntry {
     "<span class=|red|>"
     ```
     */
-    paragraph: Option<String>,
+    pub paragraph: Option<String>,
 
     /**
     Excel Column: `"“"`
@@ -164,17 +420,17 @@ pub struct InterlinearWordEntry {
     `“‘`
     ```
     */
-    start_quote: Option<String>,
+    pub start_quote: Option<String>,
 
     /**
     Excel Column: `" BSB version "`
     Examples NT values
-    ```
+    ```text
     ` [This is the] record `
     ```
     where "record" corresponds to the Greek word and "[This is the]" captures the form/context/usage of the word
     */
-    english: TranslatedWord,
+    pub english: TranslatedWord,
 
     /**
     Excel Column: `"pnc"`
@@ -225,7 +481,7 @@ pub struct InterlinearWordEntry {
     `”;`
     ````
     */
-    puncutation: Option<String>,
+    pub puncutation: Option<String>,
 
     /**
     Excel Column: `"”"`
@@ -239,7 +495,7 @@ pub struct InterlinearWordEntry {
     `”</span>`
     ```
     */
-    end_quote: Option<String>,
+    pub end_quote: Option<String>,
 
     /**
     Excel Column: `"footnotes"`
@@ -261,7 +517,7 @@ pub struct InterlinearWordEntry {
     `BYZ and TR include <i><span class=|fnv|>28</span>So the Scripture was fulfilled that says, “And He was numbered with the transgressors.”</i> See Isaiah 53:12 and Luke 22:37.`
     ```
     */
-    footnotes: Option<String>,
+    pub footnotes: Option<String>,
 
     /**
     Excel Column: `"End text"`
@@ -296,129 +552,308 @@ pub struct InterlinearWordEntry {
     `”’</span>`
     ```
     */
-    end_text: Option<String>,
+    pub end_text: Option<String>,
 }
 
+impl InterlinearWordEntry {
+    /// Decoded from [`Self::verse_id`]
+    pub fn verse(&self) -> BookChapterVerse {
+        decode_verse_id(self.verse_id).expect("verse ids are checked when converted or deserialized")
+    }
+}
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RawInterlinearEntry {
     /**
     Excel Column: `"Heb Sort"`
     */
-    hebrew_sort: u32,
+    pub hebrew_sort: u32,
 
     /**
     Excel Column: `"Greek Sort"`
     */
-    greek_sort: u32,
+    pub greek_sort: u32,
 
     /**
     Excel Column: `"BSB Sort"`
     */
-    bsb_sort: u32,
+    pub bsb_sort: u32,
 
     /**
     Excel Column: `"Verse"`
     */
-    verse_id: u32,
+    pub verse_id: u32,
 
     /**
     Excel Column: `"Language"`
     */
-    language: String,
+    pub language: String,
 
     /**
     Excel Column: `"WLC / Nestle Base TR RP WH NE NA SBL"`
     */
-    text_1: Option<String>,
+    pub text_1: Option<String>,
 
     /**
     Excel Column: `"WLC / Nestle Base {TR} ⧼RP⧽ (WH) 〈NE〉 [NA] ‹SBL› [[ECM]]"`
     */
-    text_2: Option<String>,
+    pub text_2: Option<String>,
 
     /**
     Excel Column: `"Translit"`
     */
-    transliteration: Option<String>,
+    pub transliteration: Option<String>,
 
     /**
     Excel Column: `"Parsing"`
     */
-    parsing_code: Option<String>,
+    pub parsing_code: Option<String>,
 
     /**
     Excel Column: `"Parsing"`
     */
-    parsing: Option<String>,
+    pub parsing: Option<String>,
 
     /**
     Excel Column: `"Str Heb"`
     */
-    strongs_hebrew: Option<u32>,
+    pub strongs_hebrew: Option<u32>,
 
     /**
     Excel Column: `"Str Grk"`
     */
-    strongs_greek: Option<u32>,
+    pub strongs_greek: Option<u32>,
 
     /**
     Excel Column: `"Verse"`
     */
-    verse: Option<String>,
+    pub verse: Option<String>,
 
     /**
     Excel Column: `"Hdg"`
     */
-    heading: Option<String>,
+    pub heading: Option<String>,
 
     /**
     Excel Column: `"Crossref"`
     */
-    crossref: Option<Vec<String>>,
+    pub crossref: Option<String>,
 
     /**
     Excel Column: `"Par"`
     ```
     */
-    paragraph: Option<String>,
+    pub paragraph: Option<String>,
 
     /**
     Excel Column: `"“"`
     */
-    start_quote: Option<String>,
+    pub start_quote: Option<String>,
 
     /**
     Excel Column: `" BSB version "`
     */
-    english: Option<String>,
+    pub english: Option<String>,
 
     /**
     Excel Column: `"pnc"`
     */
-    puncutation: Option<String>,
+    pub puncutation: Option<String>,
 
     /**
     Excel Column: `"”"`
     */
-    end_quote: Option<String>,
+    pub end_quote: Option<String>,
 
     /**
     Excel Column: `"footnotes"`
     */
-    footnotes: Option<String>,
+    pub footnotes: Option<String>,
 
     /**
     Excel Column: `"End text"`
     */
-    end_text: Option<String>,
+    pub end_text: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    use crate::{books::locate_verse_id, fixture::{raw_fixture, word_fixture}};
+
+    use super::*;
+
     #[test]
     fn idk() {
 
 
     }
+
+    #[test]
+    fn language() {
+        assert_eq!("Hebrew".parse(), Ok(Language::Hebrew));
+        assert_eq!("Aramaic".parse(), Ok(Language::Aramaic));
+        assert_eq!("z".parse::<Language>(), Err("Invalid Language - 'z'".to_string()));
+        assert_eq!(Language::Greek.to_string(), "Greek");
+
+        assert_eq!(Language::infer(Some(7225), None, Some(1), "בְּרֵאשִׁ֖ית"), Some(Language::Hebrew));
+        assert_eq!(Language::infer(None, Some(976), Some(40), "Βίβλος"), Some(Language::Greek));
+        assert_eq!(Language::infer(None, None, None, "Βίβλος"), Some(Language::Greek));
+        assert_eq!(Language::infer(None, None, Some(1), ""), Some(Language::Hebrew));
+        // the evidence disagrees
+        assert_eq!(Language::infer(Some(7225), None, Some(40), "Βίβλος"), None);
+        assert_eq!(Language::infer(None, None, None, "-"), None);
+    }
+
+    fn segments(word: &str) -> Vec<TranslatedTextSegment> {
+        match word.parse::<TranslatedWord>() {
+            Ok(TranslatedWord::Text(segments)) => segments,
+            other => panic!("Expected text, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn translated_word() {
+        use TranslatedTextSegment::*;
+        let space = || Space(" ".to_string());
+
+        assert_eq!(" - ".parse(), Ok(TranslatedWord::Omitted));
+        assert_eq!(" . . . ".parse(), Ok(TranslatedWord::Earlier));
+        assert_eq!(" vvv ".parse(), Ok(TranslatedWord::Later));
+
+        assert_eq!(segments(" [This is the] record "), vec![space(), Grammar("This is the".to_string()), space(), Word("record".to_string()), space()]);
+        assert_eq!(segments(" [He] said [to them], "), vec![
+            space(), Grammar("He".to_string()), space(), Word("said".to_string()), space(), Grammar("to them".to_string()), Word(",".to_string()), space(),
+        ]);
+        assert_eq!(segments(" [the [very]] end "), vec![space(), Grammar("the [very]".to_string()), space(), Word("end".to_string()), space()]);
+        assert_eq!(segments(" [was over "), vec![space(), Word("[was over".to_string()), space()]);
+        assert_eq!(segments(" was] over "), vec![space(), Word("was] over".to_string()), space()]);
+        assert_eq!(segments("  and  void "), vec![Space("  ".to_string()), Word("and  void".to_string()), space()]);
+        assert_eq!(segments(""), vec![]);
+
+        for cell in [" - ", " . . . ", " vvv ", " [This is the] record ", " [the [very]] end ", " [was over ", " was] over ", "  and  void ", "[]", ""] {
+            assert_eq!(cell.parse::<TranslatedWord>().unwrap().to_string(), cell);
+        }
+    }
+
+    #[test]
+    fn translated_word_fixture() {
+        for cell in raw_fixture().iter().filter_map(|raw| raw.english.as_deref()) {
+            assert_eq!(cell.parse::<TranslatedWord>().unwrap().to_string(), cell);
+        }
+    }
+
+    fn is_marker(cell: &str) -> bool {
+        matches!(cell.trim(), "-" | ". . ." | "vvv")
+    }
+
+    proptest! {
+        /// Anything that is not a marker is written back exactly
+        #[test]
+        fn lossless(cell in "\\PC*") {
+            prop_assume!(!is_marker(&cell));
+            prop_assert_eq!(cell.parse::<TranslatedWord>().unwrap().to_string(), cell);
+        }
+
+        /// Cells built from the observed shapes: padded words, bracketed runs (nested or unbalanced) and extra spaces
+        #[test]
+        fn lossless_shapes(parts in prop::collection::vec(prop_oneof![
+            "[A-Za-z’',;:!?-]{1,8}( [A-Za-z’']{1,8}){0,3}",
+            "\\[[A-Za-z ]{0,12}\\]",
+            "\\[[a-z ]{0,6}\\[[a-z ]{0,6}\\][a-z ]{0,6}\\]",
+            "[\\[\\]]",
+            " {1,3}",
+        ], 0..8)) {
+            let cell = format!(" {} ", parts.concat());
+            prop_assume!(!is_marker(&cell));
+            prop_assert_eq!(cell.parse::<TranslatedWord>().unwrap().to_string(), cell);
+        }
+
+        /// Markers are always written with a single space on each side
+        #[test]
+        fn markers(marker in prop_oneof![Just("-"), Just(". . ."), Just("vvv")], before in " {0,3}", after in " {0,3}") {
+            let word = format!("{before}{marker}{after}").parse::<TranslatedWord>().unwrap();
+            prop_assert!(!matches!(word, TranslatedWord::Text(_)));
+            prop_assert_eq!(word.to_string(), format!(" {marker} "));
+        }
+    }
+
+    fn transliterations(words: &[&InterlinearWordEntry]) -> Vec<String> {
+        words.iter().map(|word| word.transliteration.clone()).collect_vec()
+    }
+
+    #[test]
+    fn group() {
+        let verses = InterlinearVerseEntry::group(word_fixture(), WordOrder::Bsb);
+        assert_eq!(verses.iter().map(|verse| locate_verse_id(verse.verse_id)).collect_vec(), vec![
+            Some((1, 1, 1)),
+            Some((1, 1, 2)),
+            Some((1, 1, 3)),
+            Some((1, 31, 47)),
+            Some((40, 1, 1)),
+            Some((40, 1, 2)),
+        ]);
+        assert!(verses.iter().all(|verse| verse.words.iter().all(|word| word.verse_id == verse.verse_id)));
+        assert_eq!(verses.iter().map(|verse| verse.words.len()).sum::<usize>(), 62);
+
+        // In the beginning God created the heavens and the earth.
+        let genesis = &verses[0];
+        assert_eq!(transliterations(&genesis.words.iter().collect_vec())[..3], ["bə·rê·šîṯ", "’ĕ·lō·hîm", "bā·rā"]);
+        assert_eq!(transliterations(&genesis.words_in(WordOrder::Hebrew))[..3], ["bə·rê·šîṯ", "bā·rā", "’ĕ·lō·hîm"]);
+        assert_eq!(transliterations(&genesis.words_in(WordOrder::Original))[..3], ["bə·rê·šîṯ", "bā·rā", "’ĕ·lō·hîm"]);
+    }
+
+    #[test]
+    fn greek_order() {
+        let mut verses = InterlinearVerseEntry::group(word_fixture(), WordOrder::Greek);
+        let matthew = verses.last_mut().unwrap();
+        assert_eq!(locate_verse_id(matthew.verse_id), Some((40, 1, 2)));
+        assert!(matthew.words.iter().tuple_windows().all(|(a, b)| a.greek_sort < b.greek_sort));
+        assert_eq!(transliterations(&matthew.words.iter().collect_vec())[15..], ["tous", "adelphous", "autou"]);
+
+        // his brothers
+        let bsb = transliterations(&matthew.words_in(WordOrder::Bsb));
+        assert_eq!(bsb[15..], ["tous", "autou", "adelphous"]);
+        // Isaac (δὲ) Isaac
+        assert_eq!(bsb[3..6], ["Isaak", "de", "Isaak"]);
+
+        matthew.sort_words(WordOrder::Bsb);
+        assert_eq!(transliterations(&matthew.words.iter().collect_vec()), bsb);
+    }
+
+    #[test]
+    fn serde() -> serde_json::Result<()> {
+        assert_eq!(serde_json::to_string(&Language::Aramaic)?, r#""Aramaic""#);
+        assert_eq!(serde_json::to_string(&WordOrder::Original)?, r#""original""#);
+        assert_eq!(serde_json::to_string(&TranslatedWord::Earlier)?, r#""earlier""#);
+        assert_eq!(
+            serde_json::to_string(&" [This is the] record ".parse::<TranslatedWord>().unwrap())?,
+            r#"{"text":[{"space":" "},{"grammar":"This is the"},{"space":" "},{"word":"record"},{"space":" "}]}"#
+        );
+        for language in [Language::Hebrew, Language::Greek, Language::Aramaic] {
+            assert_eq!(serde_json::from_str::<Language>(&serde_json::to_string(&language)?)?, language);
+        }
+
+        let words = word_fixture();
+        for word in words.iter() {
+            let json = serde_json::to_string(word)?;
+            assert_eq!(&serde_json::from_str::<InterlinearWordEntry>(&json)?, word, "{json}");
+            let json = serde_json::to_string(&word.english)?;
+            assert_eq!(serde_json::from_str::<TranslatedWord>(&json)?, word.english, "{json}");
+        }
+        let verses = InterlinearVerseEntry::group(words, WordOrder::Bsb);
+        assert_eq!(serde_json::from_str::<Vec<InterlinearVerseEntry>>(&serde_json::to_string(&verses)?)?, verses);
+
+        // the verse is only written as its id, and decoded from it
+        let json = serde_json::to_value(&verses[4])?;
+        assert_eq!(json["verse_id"], 23146);
+        assert_eq!(json.get("verse"), None);
+        let mut invalid = json.clone();
+        invalid["verse_id"] = 0.into();
+        let error = serde_json::from_value::<InterlinearVerseEntry>(invalid).unwrap_err();
+        assert_eq!(error.to_string(), "Invalid verse id - '0'");
+        Ok(())
+    }
 }
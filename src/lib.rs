@@ -0,0 +1,17 @@
+pub mod books;
+pub mod columns;
+pub mod conversion;
+pub mod dataframe;
+pub mod dataset;
+pub mod delimited;
+pub mod diff;
+pub mod interlinear;
+pub mod report;
+pub mod snapshot;
+pub mod stream;
+pub mod greek;
+pub mod hebrew_parsing;
+pub mod xlsx;
+
+#[cfg(test)]
+pub(crate) mod fixture;
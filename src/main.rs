@@ -1,32 +1,167 @@
-pub mod interlinear;
-pub mod greek;
-pub mod hebrew_parsing;
+use bsb_xlsx::{columns::{ColumnMapping, PRESETS}, conversion::{convert_entries_with, LanguageMode}, dataset::{write_dataset, DatasetFormat}, delimited::{read_delimited_entries, Delimiter}, diff::WorkbookDiff, interlinear::{InterlinearWordEntry, RawInterlinearEntry}, report::QualityReport, snapshot::{source_checksum, Snapshot}, stream::RawEntryReader, xlsx::{read_raw_entries_with, ReadError}};
 
-use calamine::{deserialize_as_f64_or_none, open_workbook, RangeDeserializerBuilder, Reader, Xlsx};
-use serde::Deserialize;
+const USAGE: &str = "Usage:
+  bsb_xlsx [--columns <preset | columns.json>] <bsb_tables.xlsx | .tsv | .csv>
+  bsb_xlsx [--columns <preset | columns.json>] report <bsb_tables.xlsx | .tsv | .csv> [--json]
+  bsb_xlsx [--columns <preset | columns.json>] export <bsb_tables.xlsx | .tsv | .csv> <words.parquet | words.arrow>
+  bsb_xlsx [--columns <preset | columns.json>] diff <old.xlsx> <new.xlsx> [--json]
+  bsb_xlsx [--columns <preset | columns.json>] snapshot <bsb_tables.xlsx | .tsv | .csv> <bsb_tables.snapshot>";
 
-#[derive(Deserialize)]
-struct Record {
-    metric: String,
-    #[serde(deserialize_with = "deserialize_as_f64_or_none")]
-    value: Option<f64>,
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1).peekable();
+    let columns = match args.next_if_eq("--columns") {
+        Some(_) => column_mapping(&args.next().ok_or(USAGE)?)?,
+        None => ColumnMapping::default(),
+    };
+    match args.next().as_deref() {
+        Some("report") => {
+            let path = args.next().ok_or(USAGE)?;
+            let json = match args.next().as_deref() {
+                None => false,
+                Some("--json") => true,
+                Some(_) => Err(USAGE)?,
+            };
+            report(&path, &columns, json)
+        },
+        Some("export") => {
+            let path = args.next().ok_or(USAGE)?;
+            let output = args.next().ok_or(USAGE)?;
+            export(&path, &output, &columns)
+        },
+        Some("diff") => {
+            let old = args.next().ok_or(USAGE)?;
+            let new = args.next().ok_or(USAGE)?;
+            let json = match args.next().as_deref() {
+                None => false,
+                Some("--json") => true,
+                Some(_) => Err(USAGE)?,
+            };
+            diff(&old, &new, &columns, json)
+        },
+        Some("snapshot") => {
+            let path = args.next().ok_or(USAGE)?;
+            let output = args.next().ok_or(USAGE)?;
+            snapshot(&path, &output, &columns)
+        },
+        Some(path) => convert(path, &columns),
+        None => Err(USAGE)?,
+    }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // let path = format!("{}/tests/excel.xlsx", env!("CARGO_MANIFEST_DIR"));
-    let mut excel: Xlsx<_> = open_workbook("/home/dgmastertemple/Documents/bsb/bsb_tables_greek.xlsx")?;
+/// One of the [`PRESETS`], or the path of a JSON override file
+fn column_mapping(preset_or_path: &str) -> Result<ColumnMapping, Box<dyn std::error::Error>> {
+    if let Some(mapping) = ColumnMapping::preset(preset_or_path) {
+        return Ok(mapping);
+    }
+    let json = std::fs::read_to_string(preset_or_path)
+        .map_err(|error| format!("{preset_or_path} is not one of {} or a readable file - {error}", PRESETS.join(", ")))?;
+    Ok(json.parse::<ColumnMapping>()?)
+}
 
-    let range = excel
-        .worksheet_range("biblosinterlinear96")
-        .map_err(|_| calamine::Error::Msg("Cannot find Sheet1"))?;
+/// Reads the rows of a workbook, or of a TSV or CSV file chosen by the extension of `path`
+fn read_entries(path: &str, columns: &ColumnMapping) -> Result<Vec<RawInterlinearEntry>, ReadError> {
+    match Delimiter::from_path(path) {
+        Some(delimiter) => read_delimited_entries(path, delimiter, columns),
+        None => read_raw_entries_with(path, columns),
+    }
+}
 
-    let iter_records =
-        RangeDeserializerBuilder::with_headers(&["metric", "value"]).from_range(&range)?;
+/// Converts the rows one at a time, without keeping the words
+/// - Workbooks are streamed, while TSV and CSV files are read whole
+fn convert(path: &str, columns: &ColumnMapping) -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader;
+    let rows: Box<dyn Iterator<Item = Result<RawInterlinearEntry, calamine::Error>>> = match Delimiter::from_path(path) {
+        Some(delimiter) => Box::new(read_delimited_entries(path, delimiter, columns)?.into_iter().map(Ok)),
+        None => {
+            reader = RawEntryReader::open(path)?.with_columns(columns.clone());
+            Box::new(reader.rows()?)
+        },
+    };
+
+    let (mut words, mut warnings, mut errors) = (0, 0, 0);
+    for row in rows {
+        match InterlinearWordEntry::convert(row?, LanguageMode::Infer) {
+            Ok((_, word_warnings)) => {
+                for warning in word_warnings.iter() {
+                    eprintln!("Warning: {}", warning);
+                }
+                words += 1;
+                warnings += word_warnings.len();
+            },
+            Err(error) => {
+                eprintln!("{}", error);
+                errors += 1;
+            },
+        }
+    }
+    println!("Words: {}", words);
+    println!("Warnings: {}", warnings);
+    println!("Errors: {}", errors);
 
-    for result in iter_records {
-        let record: Record = result?;
-        println!("metric={:?}, value={:?}", record.metric, record.value);
+    Ok(())
+}
+
+/// Prints the data-quality report as Markdown, or as JSON
+fn report(path: &str, columns: &ColumnMapping, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let entries = read_entries(path, columns)?;
+    let report = QualityReport::new(&entries);
+
+    if json {
+        println!("{}", report.to_json()?);
+    } else {
+        print!("{}", report.to_markdown());
+    }
+
+    Ok(())
+}
+
+/// Writes the converted words to a Parquet or Arrow IPC dataset, chosen by the extension of `output`
+fn export(path: &str, output: &str, columns: &ColumnMapping) -> Result<(), Box<dyn std::error::Error>> {
+    let format = DatasetFormat::from_path(output).ok_or(USAGE)?;
+    let entries = read_entries(path, columns)?;
+    let (words, errors, warnings) = convert_entries_with(entries, LanguageMode::Infer);
+
+    for warning in warnings.iter() {
+        eprintln!("Warning: {}", warning);
+    }
+    for error in errors.iter() {
+        eprintln!("{}", error);
+    }
+    write_dataset(output, format, &words)?;
+    println!("Words: {}", words.len());
+
+    Ok(())
+}
+
+/// Prints the words that changed between two revisions as Markdown, or as JSON
+fn diff(old: &str, new: &str, columns: &ColumnMapping, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let diff = WorkbookDiff::new(&read_entries(old, columns)?, &read_entries(new, columns)?);
+
+    if json {
+        println!("{}", diff.to_json()?);
+    } else {
+        print!("{}", diff.to_markdown());
+    }
+
+    Ok(())
+}
+
+/// Writes the converted words to a snapshot, which [`Snapshot::load`] only reads while `path` is unchanged
+fn snapshot(path: &str, output: &str, columns: &ColumnMapping) -> Result<(), Box<dyn std::error::Error>> {
+    let checksum = source_checksum(path)?;
+    let entries = read_entries(path, columns)?;
+    let (words, errors, warnings) = convert_entries_with(entries, LanguageMode::Infer);
+
+    for warning in warnings.iter() {
+        eprintln!("Warning: {}", warning);
+    }
+    for error in errors.iter() {
+        eprintln!("{}", error);
     }
+    let snapshot = Snapshot::new(words, checksum);
+    snapshot.write(output)?;
+    println!("Verses: {}", snapshot.verses().len());
+    println!("Words: {}", snapshot.verses().iter().map(|verse| verse.words.len()).sum::<usize>());
 
     Ok(())
 }
@@ -0,0 +1,392 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::{greek::word::GreekWordParsing, hebrew_parsing::HebrewWordParsing, interlinear::{Language, RawInterlinearEntry, TranslatedTextSegment, TranslatedWord}};
+
+/// `<p class=|reg|>` captures `("", "p", "")`, `</span>` captures `("/", "span", "")`, `<br />` captures `("", "br", "/")`
+static HTML_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<(/?)([A-Za-z][A-Za-z0-9]*)[^>]*?(/?)>").unwrap());
+
+/// Tags that are never closed
+const VOID_TAGS: &[&str] = &["br", "hr", "img", "wbr"];
+
+/// The kind of anomaly found in a row
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// `"Parsing"` code that [`GreekWordParsing::parse`] or [`HebrewWordParsing::parse`] rejects
+    UnknownParsingCode,
+    /// Greek `"Parsing"` description that does not match its `"Parsing"` code
+    ParsingMismatch,
+    /// `"Language"` that is not `Hebrew`, `Greek` or `Aramaic`
+    UnknownLanguage,
+    /// No Strong's number in the column for the language of the row
+    MissingStrongs,
+    /// HTML tag closed without being opened, or opened and never closed
+    UnbalancedHtml,
+    /// Quotation mark closed without being opened, or opened and never closed
+    UnmatchedQuote,
+    /// `" BSB version "` without any words, that is not the [`TranslatedWord::Omitted`] marker
+    EmptyEnglish,
+    /// Sort number skipped or repeated
+    SortGap,
+}
+
+impl IssueKind {
+    pub fn title(&self) -> &'static str {
+        match self {
+            IssueKind::UnknownParsingCode => "Unknown parsing code",
+            IssueKind::ParsingMismatch => "Parsing mismatch",
+            IssueKind::UnknownLanguage => "Unknown language",
+            IssueKind::MissingStrongs => "Missing Strong's number",
+            IssueKind::UnbalancedHtml => "Unbalanced HTML tag",
+            IssueKind::UnmatchedQuote => "Unmatched quote",
+            IssueKind::EmptyEnglish => "Empty English",
+            IssueKind::SortGap => "Sort order gap",
+        }
+    }
+}
+
+impl Display for IssueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.title())
+    }
+}
+
+/// A single anomaly
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// Excel Column `"BSB Sort"` of the row
+    pub bsb_sort: u32,
+    /// Excel column title of the cell
+    pub column: &'static str,
+    pub detail: String,
+}
+
+/// Every anomaly found in a workbook, grouped by [`IssueKind`] and ordered by `"BSB Sort"`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct QualityReport {
+    pub rows: usize,
+    pub issues: Vec<Issue>,
+}
+
+impl QualityReport {
+    /// Scans every row
+    /// - HTML tags and quotation marks are matched across rows in BSB order, since they usually span several words
+    pub fn new(raws: &[RawInterlinearEntry]) -> Self {
+        let mut issues = vec![];
+        for raw in raws {
+            check_row(raw, &mut issues);
+        }
+
+        let bsb_order = raws.iter().sorted_by_key(|raw| raw.bsb_sort).collect_vec();
+        check_html(&bsb_order, &mut issues);
+        check_quotes(&bsb_order, &mut issues);
+
+        check_sort(raws, "Heb Sort", |raw| raw.hebrew_sort, &mut issues);
+        check_sort(raws, "Greek Sort", |raw| raw.greek_sort, &mut issues);
+        check_sort(raws, "BSB Sort", |raw| raw.bsb_sort, &mut issues);
+
+        issues.sort_by_key(|issue| (issue.kind, issue.bsb_sort));
+        Self { rows: raws.len(), issues }
+    }
+
+    pub fn count(&self, kind: IssueKind) -> usize {
+        self.issues.iter().filter(|issue| issue.kind == kind).count()
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("# Data Quality Report\n\nRows: {}\n\nIssues: {}\n", self.rows, self.issues.len());
+        if self.issues.is_empty() {
+            return markdown;
+        }
+
+        markdown.push_str("\n| Issue | Count |\n| --- | --- |\n");
+        let groups = self.issues.iter().chunk_by(|issue| issue.kind);
+        let groups = groups.into_iter().map(|(kind, issues)| (kind, issues.collect_vec())).collect_vec();
+        for (kind, issues) in groups.iter() {
+            markdown.push_str(&format!("| {} | {} |\n", kind, issues.len()));
+        }
+
+        for (kind, issues) in groups.iter() {
+            markdown.push_str(&format!("\n## {}\n\n| BSB Sort | Column | Detail |\n| --- | --- | --- |\n", kind));
+            for issue in issues {
+                markdown.push_str(&format!("| {} | {} | {} |\n", issue.bsb_sort, escape(issue.column.trim()), escape(&issue.detail)));
+            }
+        }
+        markdown
+    }
+}
+
+/// `|` would end the Markdown table cell
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn check_row(raw: &RawInterlinearEntry, issues: &mut Vec<Issue>) {
+    let mut issue = |kind: IssueKind, column: &'static str, detail: String| issues.push(Issue { kind, bsb_sort: raw.bsb_sort, column, detail });
+
+    match raw.language.parse::<Language>() {
+        Ok(Language::Greek) => {
+            if let Some(code) = raw.parsing_code.as_deref().filter(|code| !code.is_empty()) {
+                match GreekWordParsing::parse(code) {
+                    Ok(parsing) => if let Some(mismatch) = parsing_mismatch(code, &parsing, raw.parsing.as_deref()) {
+                        issue(IssueKind::ParsingMismatch, "Parsing", mismatch);
+                    },
+                    Err(reason) => issue(IssueKind::UnknownParsingCode, "Parsing", reason.to_string()),
+                }
+            }
+            if raw.strongs_greek.is_none() {
+                issue(IssueKind::MissingStrongs, "Str Grk", format!("No Strong's number for '{}'", raw.text_1.as_deref().unwrap_or_default()));
+            }
+        },
+        Ok(Language::Hebrew | Language::Aramaic) => {
+            if let Some(code) = raw.parsing_code.as_deref().filter(|code| !code.is_empty())
+                && let Err(reason) = HebrewWordParsing::parse(code)
+            {
+                issue(IssueKind::UnknownParsingCode, "Parsing", format!("'{code}': {reason}"));
+            }
+            if raw.strongs_hebrew.is_none() {
+                issue(IssueKind::MissingStrongs, "Str Heb", format!("No Strong's number for '{}'", raw.text_1.as_deref().unwrap_or_default()));
+            }
+        },
+        Err(reason) => issue(IssueKind::UnknownLanguage, "Language", reason),
+    }
+
+    match raw.english.as_deref() {
+        None => issue(IssueKind::EmptyEnglish, " BSB version ", "Translation is missing".to_string()),
+        Some(cell) => {
+            let Ok(english) = cell.parse::<TranslatedWord>();
+            if let TranslatedWord::Text(segments) = english
+                && segments.iter().all(|segment| match segment {
+                    TranslatedTextSegment::Word(word) | TranslatedTextSegment::Grammar(word) => word.trim().is_empty(),
+                    TranslatedTextSegment::Space(_) => true,
+                })
+            {
+                issue(IssueKind::EmptyEnglish, " BSB version ", format!("No words in '{cell}'"));
+            }
+        },
+    }
+}
+
+/// Decodes the `"Parsing"` description and compares it to the decoded code
+fn parsing_mismatch(code: &str, parsing: &GreekWordParsing, description: Option<&str>) -> Option<String> {
+    let description = description.filter(|description| !description.is_empty())?;
+    match GreekWordParsing::from_description(description) {
+        Ok(described) if described == *parsing => None,
+        Ok(described) => Some(format!("'{code}' is '{}' but the description '{description}' is '{}'", parsing.describe(), described.to_code())),
+        Err(reason) => Some(reason.to_string()),
+    }
+}
+
+/// The columns that may contain markup or quotation marks, in the order they are displayed
+fn markup_cells(raw: &RawInterlinearEntry) -> [(&'static str, Option<&str>); 5] {
+    [
+        ("Par", raw.paragraph.as_deref()),
+        ("“", raw.start_quote.as_deref()),
+        ("pnc", raw.puncutation.as_deref()),
+        ("”", raw.end_quote.as_deref()),
+        ("End text", raw.end_text.as_deref()),
+    ]
+}
+
+/// Matches tags opened and closed in the paragraph, quote and end text columns
+/// - `<p>` is never closed explicitly: it ends at the next paragraph or at the end of its parent
+fn check_html(raws: &[&RawInterlinearEntry], issues: &mut Vec<Issue>) {
+    // (tag, BSB Sort, column) of every open tag
+    let mut open: Vec<(String, u32, &'static str)> = vec![];
+
+    for raw in raws {
+        for (column, cell) in markup_cells(raw) {
+            let Some(cell) = cell else { continue };
+            for captures in HTML_TAG.captures_iter(cell) {
+                let tag = captures[2].to_lowercase();
+                if !captures[3].is_empty() || VOID_TAGS.contains(&tag.as_str()) {
+                    continue;
+                }
+
+                if captures[1].is_empty() {
+                    if (tag == "p" || tag == "div") && open.last().is_some_and(|(last, _, _)| last == "p") {
+                        open.pop();
+                    }
+                    open.push((tag, raw.bsb_sort, column));
+                    continue;
+                }
+
+                if tag != "p" && open.last().is_some_and(|(last, _, _)| last == "p") {
+                    open.pop();
+                }
+                match open.iter().rposition(|(name, _, _)| *name == tag) {
+                    Some(index) => {
+                        for (name, bsb_sort, column) in open.drain(index..).skip(1) {
+                            issues.push(Issue { kind: IssueKind::UnbalancedHtml, bsb_sort, column, detail: format!("<{name}> is closed by </{tag}> at BSB Sort {}", raw.bsb_sort) });
+                        }
+                    },
+                    None => issues.push(Issue { kind: IssueKind::UnbalancedHtml, bsb_sort: raw.bsb_sort, column, detail: format!("</{tag}> was never opened") }),
+                }
+            }
+        }
+    }
+
+    for (name, bsb_sort, column) in open.into_iter().filter(|(name, _, _)| name != "p") {
+        issues.push(Issue { kind: IssueKind::UnbalancedHtml, bsb_sort, column, detail: format!("<{name}> is never closed") });
+    }
+}
+
+/// Matches `“”` and `‘’` in the paragraph, quote and end text columns
+/// - A quotation continued into a new paragraph is opened again without being closed
+/// - `’` without an open `‘` is an apostrophe
+fn check_quotes(raws: &[&RawInterlinearEntry], issues: &mut Vec<Issue>) {
+    // (quote, BSB Sort, column) of every open quote
+    let mut open: Vec<(char, u32, &'static str)> = vec![];
+    let mut new_paragraph = false;
+
+    for raw in raws {
+        if raw.paragraph.as_deref().is_some_and(|paragraph| !paragraph.is_empty()) {
+            new_paragraph = true;
+        }
+        for (column, cell) in markup_cells(raw) {
+            for c in cell.unwrap_or_default().chars() {
+                let mut issue = |detail: String| issues.push(Issue { kind: IssueKind::UnmatchedQuote, bsb_sort: raw.bsb_sort, column, detail });
+                match c {
+                    '“' | '‘' => {
+                        if open.last().is_some_and(|(last, _, _)| *last == c) {
+                            if !new_paragraph {
+                                issue(format!("{c} is opened inside {c}"));
+                            }
+                        } else {
+                            open.push((c, raw.bsb_sort, column));
+                        }
+                        new_paragraph = false;
+                    },
+                    '”' | '’' => {
+                        let opening = if c == '”' { '“' } else { '‘' };
+                        match open.iter().rposition(|(quote, _, _)| *quote == opening) {
+                            Some(index) => {
+                                for (quote, bsb_sort, _) in open.drain(index..).skip(1) {
+                                    issue(format!("{quote} from BSB Sort {bsb_sort} is never closed"));
+                                }
+                            },
+                            None if c == '’' => (),
+                            None => issue(format!("{c} was never opened")),
+                        }
+                    },
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    for (quote, bsb_sort, column) in open {
+        issues.push(Issue { kind: IssueKind::UnmatchedQuote, bsb_sort, column, detail: format!("{quote} is never closed") });
+    }
+}
+
+/// Sort numbers of a column should count up by one, ignoring the rows where it is `0` (the other testament)
+fn check_sort(raws: &[RawInterlinearEntry], column: &'static str, sort: impl Fn(&RawInterlinearEntry) -> u32, issues: &mut Vec<Issue>) {
+    let sorted = raws.iter()
+        .filter(|raw| sort(raw) != 0)
+        .sorted_by_key(|raw| sort(raw))
+        .collect_vec();
+
+    for (previous, raw) in sorted.iter().tuple_windows() {
+        let (previous, current) = (sort(previous), sort(raw));
+        let detail = match current - previous {
+            0 => format!("{current} is repeated"),
+            1 => continue,
+            2 => format!("{} is missing", previous + 1),
+            _ => format!("{}-{} are missing", previous + 1, current - 1),
+        };
+        issues.push(Issue { kind: IssueKind::SortGap, bsb_sort: raw.bsb_sort, column, detail });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fixture::raw_fixture;
+
+    use super::*;
+
+    fn issues(report: &QualityReport, kind: IssueKind) -> Vec<(u32, &'static str, &str)> {
+        report.issues.iter()
+            .filter(|issue| issue.kind == kind)
+            .map(|issue| (issue.bsb_sort, issue.column, issue.detail.as_str()))
+            .collect_vec()
+    }
+
+    #[test]
+    fn bsb() {
+        let report = QualityReport::new(&raw_fixture());
+        assert_eq!(report.rows, 62);
+
+        // the sample only has a few verses, and some words have no Strong's number
+        assert_eq!(issues(&report, IssueKind::SortGap), vec![
+            (20001, "BSB Sort", "28-20000 are missing"),
+            (20002, "Heb Sort", "28-20000 are missing"),
+            (300001, "BSB Sort", "20010-300000 are missing"),
+        ]);
+        assert_eq!(report.count(IssueKind::MissingStrongs), 2);
+        assert_eq!(report.issues.len(), 5);
+    }
+
+    #[test]
+    fn bad_cells() {
+        let mut raws = raw_fixture();
+        raws[0].language = "z".to_string();
+        raws[4].parsing_code = Some("V-Qal-Perfect-3ms".to_string());
+        raws[1].english = Some(" [] ".to_string());
+        raws[2].end_text = Some("</span>".to_string());
+        raws[3].paragraph = Some("<div class=|x|><p class=|reg|>".to_string());
+        raws[4].end_text = Some("<span class=|red|><i>".to_string());
+        raws[7].end_text = Some("</span>".to_string());
+        raws[5].start_quote = Some("‘".to_string());
+        raws[6].puncutation = Some(".”".to_string());
+        let matthew = raws.iter().position(|raw| raw.text_1.as_deref() == Some("Βίβλος")).unwrap();
+        raws[matthew].parsing_code = Some("N-XYZ".to_string());
+        raws[matthew + 1].strongs_greek = None;
+        raws[matthew + 3].parsing = Some("Noun - Genitive Masculine Plural".to_string());
+        raws[matthew + 4].parsing = Some("Noun - Genitive Masculine Singularr".to_string());
+        raws[matthew + 2].greek_sort = raws[matthew + 1].greek_sort;
+
+        let report = QualityReport::new(&raws);
+        let sort = |index: usize| raws[index].bsb_sort;
+        assert_eq!(issues(&report, IssueKind::UnknownLanguage), vec![(sort(0), "Language", "Invalid Language - 'z'")]);
+        assert_eq!(issues(&report, IssueKind::EmptyEnglish), vec![(sort(1), " BSB version ", "No words in ' [] '")]);
+        assert_eq!(issues(&report, IssueKind::UnbalancedHtml), vec![
+            (sort(2), "End text", "</span> was never opened"),
+            (sort(3), "Par", "<div> is never closed"),
+            (sort(4), "End text", &format!("<i> is closed by </span> at BSB Sort {}", sort(7))),
+        ]);
+        assert_eq!(issues(&report, IssueKind::UnmatchedQuote), vec![
+            (sort(5), "“", "‘ is never closed"),
+            (sort(6), "pnc", "” was never opened"),
+        ]);
+        assert_eq!(issues(&report, IssueKind::UnknownParsingCode), vec![
+            (sort(4), "Parsing", "'V-Qal-Perfect-3ms': Invalid Conjugation - 'Perfect'"),
+            (raws[matthew].bsb_sort, "Parsing", "Invalid Case - 'X' at segment 1, offset 0 of 'N-XYZ'"),
+        ]);
+        assert_eq!(issues(&report, IssueKind::ParsingMismatch), vec![
+            (raws[matthew + 3].bsb_sort, "Parsing", "'N-GMS' is 'Noun - Genitive Masculine Singular' but the description 'Noun - Genitive Masculine Plural' is 'N-GMP'"),
+            (raws[matthew + 4].bsb_sort, "Parsing", "Unknown component - 'Singularr' at segment 1, offset 19 of 'Noun - Genitive Masculine Singularr'"),
+        ]);
+        assert_eq!(report.count(IssueKind::MissingStrongs), 3);
+        assert!(issues(&report, IssueKind::SortGap).contains(&(300003, "Greek Sort", "2 is repeated")));
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("| Unknown language | 1 |"));
+        assert!(markdown.contains(&format!("## Unbalanced HTML tag\n\n| BSB Sort | Column | Detail |\n| --- | --- | --- |\n| {} | End text | </span> was never opened |\n", sort(2))));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["rows"], 62);
+        assert_eq!(json["issues"][0]["kind"], "unknown_parsing_code");
+        assert_eq!(json["issues"].as_array().unwrap().len(), report.issues.len());
+    }
+}
@@ -0,0 +1,469 @@
+use std::{collections::HashMap, fmt::Display, path::Path};
+
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::{books::decode_verse_id, dataframe::{morphology, MORPHOLOGY_COLUMNS}, interlinear::{InterlinearVerseEntry, InterlinearWordEntry, Language, TranslatedWord, WordOrder}};
+
+/// Written after the magic bytes
+/// - Bumped whenever the layout changes, so that old snapshots are rejected instead of misread
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+const MAGIC: &[u8; 8] = b"BSBSNAP\0";
+
+/// The fewest bytes of a string, or of a string index
+/// - Like [`VERSE_SIZE`] and [`WORD_SIZE`], so that a count that can't fit in the rest of the file is rejected before it is allocated
+const STRING_SIZE: usize = 4;
+/// Id and word count
+const VERSE_SIZE: usize = 8;
+/// Sort ids, language, optional cells, 6 string indexes and morphology
+const WORD_SIZE: usize = 12 + 1 + 2 + 6 * STRING_SIZE + 8;
+
+/// Bits of each of [`MORPHOLOGY_COLUMNS`] in a packed code, so that a column can have 127 values and all 9 fit in a `u64`
+const MORPHOLOGY_BITS: usize = 7;
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(std::io::Error),
+    /// The file doesn't start with the snapshot magic bytes
+    NotASnapshot,
+    /// The file has another [`SNAPSHOT_VERSION`]
+    Version(u32),
+    /// The checksum of the source in the snapshot and the checksum of the source now
+    Stale { snapshot: u64, source: u64 },
+    /// The file is truncated or invalid
+    Corrupt(String),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{}", error),
+            Self::NotASnapshot => write!(f, "Not a snapshot"),
+            Self::Version(version) => write!(f, "Snapshot version {version} is not supported, expected {SNAPSHOT_VERSION}"),
+            Self::Stale { snapshot, source } => write!(f, "Snapshot is stale - it was written from a source with checksum {snapshot:016x}, but the source now has checksum {source:016x}"),
+            Self::Corrupt(reason) => write!(f, "Snapshot is corrupt - {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<std::io::Error> for SnapshotError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// The checksum of a workbook, or of a TSV or CSV file, written to the snapshot of its words
+pub fn source_checksum(path: impl AsRef<Path>) -> std::io::Result<u64> {
+    Ok(xxh3_64(&std::fs::read(path)?))
+}
+
+/**
+Every verse and word of a workbook, in a binary file that loads without converting the workbook again
+
+| Section | Layout |
+| --- | --- |
+| Header | `BSBSNAP\0`, [`SNAPSHOT_VERSION`] as `u32`, [`source_checksum`] as `u64` |
+| Strings | count, then each as its length and UTF-8 bytes, where every other section refers to a string by its index |
+| Morphology | for each of [`MORPHOLOGY_COLUMNS`], its values |
+| Verses | count, then for each verse its id, its word count and its words |
+
+Numbers are little-endian, and counts, lengths and indexes are `u32`.
+
+Each word has a `u16` of which optional cells it has, and its morphology packed into a `u64` of [`MORPHOLOGY_BITS`] per column,
+where `0` is no value and otherwise `1 +` the index of the value of the column.
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub source_checksum: u64,
+    /// Every verse in canonical order, with its words in BSB order
+    verses: Vec<InterlinearVerseEntry>,
+    /// The values of each of [`MORPHOLOGY_COLUMNS`]
+    morphology_values: Vec<Vec<String>>,
+    /// The packed morphology of every word of `verses`
+    morphology: Vec<Vec<u64>>,
+}
+
+/// The optional cells of a word other than the Strong's numbers and `"Crossref"`, in the order of their bits
+fn optional_cells(word: &InterlinearWordEntry) -> [&Option<String>; 7] {
+    [&word.heading, &word.paragraph, &word.start_quote, &word.puncutation, &word.end_quote, &word.footnotes, &word.end_text]
+}
+
+fn language_code(language: Language) -> u8 {
+    match language {
+        Language::Hebrew => 0,
+        Language::Greek => 1,
+        Language::Aramaic => 2,
+    }
+}
+
+/// Assigns each distinct string an index, in the order they are first seen
+#[derive(Default)]
+struct Interner<'a> {
+    indexes: HashMap<&'a str, u32>,
+    strings: Vec<&'a str>,
+}
+
+impl<'a> Interner<'a> {
+    /// Writes the index of a string
+    fn write(&mut self, bytes: &mut Vec<u8>, string: &'a str) {
+        let index = *self.indexes.entry(string).or_insert_with(|| {
+            self.strings.push(string);
+            self.strings.len() as u32 - 1
+        });
+        bytes.extend(index.to_le_bytes());
+    }
+}
+
+/// Reads the sections of a snapshot in order
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], SnapshotError> {
+        let Some((bytes, rest)) = self.bytes.split_first_chunk::<N>() else {
+            Err(SnapshotError::Corrupt("Unexpected end of file".to_string()))?
+        };
+        self.bytes = rest;
+        Ok(*bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, SnapshotError> {
+        Ok(u8::from_le_bytes(self.take()?))
+    }
+
+    fn u16(&mut self) -> Result<u16, SnapshotError> {
+        Ok(u16::from_le_bytes(self.take()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, SnapshotError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, SnapshotError> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+
+    /// A count of items of at least `size` bytes, which must all fit in the rest of the file
+    fn count(&mut self, size: usize) -> Result<usize, SnapshotError> {
+        let count = self.u32()? as usize;
+        if count.saturating_mul(size) > self.bytes.len() {
+            Err(SnapshotError::Corrupt(format!("{count} items don't fit in the last {} bytes", self.bytes.len())))?
+        }
+        Ok(count)
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], SnapshotError> {
+        if self.bytes.len() < length {
+            Err(SnapshotError::Corrupt("Unexpected end of file".to_string()))?
+        }
+        let (bytes, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+
+    fn string<'s>(&mut self, strings: &'s [String]) -> Result<&'s String, SnapshotError> {
+        let index = self.u32()?;
+        strings.get(index as usize).ok_or_else(|| SnapshotError::Corrupt(format!("Invalid string index - {index}")))
+    }
+}
+
+impl Snapshot {
+    /// Groups the words into verses and packs their morphology
+    pub fn new(words: Vec<InterlinearWordEntry>, source_checksum: u64) -> Self {
+        let verses = InterlinearVerseEntry::group(words, WordOrder::Bsb);
+        let mut values: Vec<Vec<&'static str>> = vec![vec![]; MORPHOLOGY_COLUMNS.len()];
+
+        let morphology = verses.iter()
+            .map(|verse| verse.words.iter().map(|word| pack(&mut values, morphology(word))).collect())
+            .collect();
+        let morphology_values = values.into_iter()
+            .map(|values| values.into_iter().map(String::from).collect())
+            .collect();
+        Self { source_checksum, verses, morphology_values, morphology }
+    }
+
+    pub fn verses(&self) -> &[InterlinearVerseEntry] {
+        &self.verses
+    }
+
+    pub fn into_verses(self) -> Vec<InterlinearVerseEntry> {
+        self.verses
+    }
+
+    /// The value of each of [`MORPHOLOGY_COLUMNS`] for a word of a verse, without parsing its `"Parsing"` code
+    pub fn morphology(&self, verse: usize, word: usize) -> Option<[Option<&str>; 9]> {
+        let code = *self.morphology.get(verse)?.get(word)?;
+        Some(std::array::from_fn(|column| {
+            unpack(code, column).checked_sub(1).map(|index| self.morphology_values[column][index].as_str())
+        }))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut strings = Interner::default();
+        let mut body = Vec::new();
+
+        // the strings of the English cells are owned by this function
+        let english = self.verses.iter()
+            .map(|verse| verse.words.iter().map(|word| word.english.to_string()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        for values in self.morphology_values.iter() {
+            body.extend((values.len() as u32).to_le_bytes());
+            for value in values {
+                strings.write(&mut body, value);
+            }
+        }
+
+        body.extend((self.verses.len() as u32).to_le_bytes());
+        for ((verse, codes), english) in self.verses.iter().zip(&self.morphology).zip(&english) {
+            body.extend(verse.verse_id.to_le_bytes());
+            body.extend((verse.words.len() as u32).to_le_bytes());
+
+            for ((word, code), english) in verse.words.iter().zip(codes).zip(english) {
+                for sort in [word.hebrew_sort, word.greek_sort, word.bsb_sort] {
+                    body.extend(sort.to_le_bytes());
+                }
+                body.push(language_code(word.language));
+
+                let present = [word.strongs_hebrew.is_some(), word.strongs_greek.is_some(), word.crossref.is_some()].into_iter()
+                    .chain(optional_cells(word).map(Option::is_some))
+                    .enumerate()
+                    .fold(0u16, |present, (bit, is_some)| present | ((is_some as u16) << bit));
+                body.extend(present.to_le_bytes());
+
+                for strongs in [word.strongs_hebrew, word.strongs_greek].into_iter().flatten() {
+                    body.extend(strongs.to_le_bytes());
+                }
+                if let Some(references) = &word.crossref {
+                    body.extend((references.len() as u32).to_le_bytes());
+                    for reference in references {
+                        strings.write(&mut body, reference);
+                    }
+                }
+                for cell in optional_cells(word).into_iter().flatten() {
+                    strings.write(&mut body, cell);
+                }
+                for cell in [&word.text_1, &word.text_2, &word.transliteration, &word.parsing_code, &word.parsing, english] {
+                    strings.write(&mut body, cell);
+                }
+                body.extend(code.to_le_bytes());
+            }
+        }
+
+        let mut bytes = Vec::with_capacity(body.len() * 2);
+        bytes.extend(MAGIC);
+        bytes.extend(SNAPSHOT_VERSION.to_le_bytes());
+        bytes.extend(self.source_checksum.to_le_bytes());
+        bytes.extend((strings.strings.len() as u32).to_le_bytes());
+        for string in strings.strings.iter() {
+            bytes.extend((string.len() as u32).to_le_bytes());
+            bytes.extend(string.as_bytes());
+        }
+        bytes.extend(body);
+        bytes
+    }
+
+    /// Reads a snapshot, if it was written from the source with `source_checksum`
+    pub fn from_bytes(bytes: &[u8], source_checksum: u64) -> Result<Self, SnapshotError> {
+        let mut cursor = Cursor { bytes };
+        if cursor.take::<8>().ok().as_ref() != Some(MAGIC) {
+            Err(SnapshotError::NotASnapshot)?
+        }
+        let version = cursor.u32()?;
+        if version != SNAPSHOT_VERSION {
+            Err(SnapshotError::Version(version))?
+        }
+        let snapshot_checksum = cursor.u64()?;
+        if snapshot_checksum != source_checksum {
+            Err(SnapshotError::Stale { snapshot: snapshot_checksum, source: source_checksum })?
+        }
+
+        let strings = (0..cursor.count(STRING_SIZE)?)
+            .map(|_| {
+                let length = cursor.u32()? as usize;
+                String::from_utf8(cursor.bytes(length)?.to_vec()).map_err(|error| SnapshotError::Corrupt(error.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let morphology_values = MORPHOLOGY_COLUMNS.iter()
+            .map(|_| (0..cursor.count(STRING_SIZE)?).map(|_| cursor.string(&strings).cloned()).collect::<Result<Vec<_>, _>>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let verse_count = cursor.count(VERSE_SIZE)?;
+        let mut verses = Vec::with_capacity(verse_count);
+        let mut morphology = Vec::with_capacity(verse_count);
+        for _ in 0..verse_count {
+            let verse_id = cursor.u32()?;
+            if decode_verse_id(verse_id).is_none() {
+                Err(SnapshotError::Corrupt(format!("Invalid verse id - {verse_id}")))?
+            }
+            let word_count = cursor.count(WORD_SIZE)?;
+            let mut words = Vec::with_capacity(word_count);
+            let mut codes = Vec::with_capacity(word_count);
+
+            for _ in 0..word_count {
+                let (hebrew_sort, greek_sort, bsb_sort) = (cursor.u32()?, cursor.u32()?, cursor.u32()?);
+                let language = match cursor.u8()? {
+                    0 => Language::Hebrew,
+                    1 => Language::Greek,
+                    2 => Language::Aramaic,
+                    code => Err(SnapshotError::Corrupt(format!("Invalid language - {code}")))?,
+                };
+                let present = cursor.u16()?;
+                let is_present = |bit: usize| present & (1 << bit) != 0;
+
+                let strongs_hebrew = is_present(0).then(|| cursor.u32()).transpose()?;
+                let strongs_greek = is_present(1).then(|| cursor.u32()).transpose()?;
+                let crossref = is_present(2)
+                    .then(|| (0..cursor.count(STRING_SIZE)?).map(|_| cursor.string(&strings).cloned()).collect::<Result<Vec<_>, _>>())
+                    .transpose()?;
+                let mut bit = 2;
+                let mut optional = || {
+                    bit += 1;
+                    is_present(bit).then(|| cursor.string(&strings).cloned()).transpose()
+                };
+                let (heading, paragraph, start_quote) = (optional()?, optional()?, optional()?);
+                let (puncutation, end_quote, footnotes, end_text) = (optional()?, optional()?, optional()?, optional()?);
+
+                words.push(InterlinearWordEntry {
+                    hebrew_sort,
+                    greek_sort,
+                    bsb_sort,
+                    verse_id,
+                    language,
+                    strongs_hebrew,
+                    strongs_greek,
+                    crossref,
+                    heading,
+                    paragraph,
+                    start_quote,
+                    puncutation,
+                    end_quote,
+                    footnotes,
+                    end_text,
+                    text_1: cursor.string(&strings)?.clone(),
+                    text_2: cursor.string(&strings)?.clone(),
+                    transliteration: cursor.string(&strings)?.clone(),
+                    parsing_code: cursor.string(&strings)?.clone(),
+                    parsing: cursor.string(&strings)?.clone(),
+                    english: cursor.string(&strings)?.parse::<TranslatedWord>().unwrap_or_else(|never| match never {}),
+                });
+                let code = cursor.u64()?;
+                if morphology_values.iter().enumerate().any(|(column, values)| unpack(code, column) > values.len()) {
+                    Err(SnapshotError::Corrupt(format!("Invalid morphology code - {code:x}")))?
+                }
+                codes.push(code);
+            }
+            verses.push(InterlinearVerseEntry { verse_id, words });
+            morphology.push(codes);
+        }
+
+        if !cursor.bytes.is_empty() {
+            Err(SnapshotError::Corrupt(format!("{} bytes after the last verse", cursor.bytes.len())))?
+        }
+        Ok(Self { source_checksum, verses, morphology_values, morphology })
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        Ok(std::fs::write(path, self.to_bytes())?)
+    }
+
+    /// Reads a snapshot, if it was written from the source with `source_checksum`
+    pub fn read(path: impl AsRef<Path>, source_checksum: u64) -> Result<Self, SnapshotError> {
+        Self::from_bytes(&std::fs::read(path)?, source_checksum)
+    }
+
+    /// Reads a snapshot, if it was written from the source at `source` as it is now
+    pub fn load(path: impl AsRef<Path>, source: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        Self::read(path, source_checksum(source)?)
+    }
+}
+
+/// Packs the morphology of a word, adding the values that haven't been seen to `values`
+fn pack(values: &mut [Vec<&'static str>], morphology: [Option<&'static str>; 9]) -> u64 {
+    let mut code = 0;
+    for (column, value) in morphology.into_iter().enumerate() {
+        let Some(value) = value else { continue };
+        let index = match values[column].iter().position(|other| *other == value) {
+            Some(index) => index,
+            None => {
+                values[column].push(value);
+                values[column].len() - 1
+            },
+        };
+        // every column is one of a few Greek or Hebrew components, so this would be a new component value
+        assert!(index + 1 < 1 << MORPHOLOGY_BITS, "{} has more than {} values", MORPHOLOGY_COLUMNS[column], (1 << MORPHOLOGY_BITS) - 1);
+        code |= (index as u64 + 1) << (column * MORPHOLOGY_BITS);
+    }
+    code
+}
+
+/// The value of a column in a packed code
+fn unpack(code: u64, column: usize) -> usize {
+    (code >> (column * MORPHOLOGY_BITS)) as usize & ((1 << MORPHOLOGY_BITS) - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fixture::{fixture_path, word_fixture};
+
+    use super::*;
+
+    #[test]
+    fn round_trip() -> Result<(), SnapshotError> {
+        let words = word_fixture();
+        let snapshot = Snapshot::new(words.clone(), source_checksum(fixture_path())?);
+        let path = std::env::temp_dir().join(format!("bsb_xlsx_snapshot_{}.bin", std::process::id()));
+        snapshot.write(&path)?;
+        let read = Snapshot::load(&path, fixture_path());
+        std::fs::remove_file(&path)?;
+
+        let read = read?;
+        assert_eq!(read, snapshot);
+        assert_eq!(read.verses(), InterlinearVerseEntry::group(words, WordOrder::Bsb));
+
+        for (verse_index, verse) in read.verses().iter().enumerate() {
+            for (word_index, word) in verse.words.iter().enumerate() {
+                assert_eq!(read.morphology(verse_index, word_index), Some(morphology(word)), "{}", word.parsing_code);
+            }
+        }
+        assert_eq!(read.morphology(read.verses().len(), 0), None);
+
+        // Genesis 1:1 and Matthew 1:1 both start with a feminine noun, packed to the same values
+        let [hebrew, greek] = [0, 4].map(|verse| read.morphology(verse, 0).unwrap());
+        assert_eq!([hebrew[0], hebrew[6]], [Some("Noun"), Some("Feminine")]);
+        assert_eq!([greek[0], greek[6]], [Some("Noun"), Some("Feminine")]);
+        Ok(())
+    }
+
+    #[test]
+    fn rejected() {
+        let snapshot = Snapshot::new(word_fixture(), 1);
+        let bytes = snapshot.to_bytes();
+
+        assert!(matches!(Snapshot::from_bytes(&bytes, 2), Err(SnapshotError::Stale { snapshot: 1, source: 2 })));
+
+        let mut version = bytes.clone();
+        version[8..12].copy_from_slice(&(SNAPSHOT_VERSION + 1).to_le_bytes());
+        assert_eq!(Snapshot::from_bytes(&version, 1).unwrap_err().to_string(), format!("Snapshot version {} is not supported, expected {SNAPSHOT_VERSION}", SNAPSHOT_VERSION + 1));
+
+        assert!(matches!(Snapshot::from_bytes(b"PK\x03\x04", 1), Err(SnapshotError::NotASnapshot)));
+        assert_eq!(Snapshot::from_bytes(&bytes[..bytes.len() - 1], 1).unwrap_err().to_string(), "Snapshot is corrupt - Unexpected end of file");
+
+        // counts are checked against the rest of the file before anything is allocated for them
+        let mut words = bytes[..20].to_vec();
+        // no strings, no morphology values, and one verse of Genesis 1:1
+        words.extend([0; 4 + 9 * 4]);
+        words.extend(1u32.to_le_bytes());
+        words.extend(1u32.to_le_bytes());
+        words.extend(u32::MAX.to_le_bytes());
+        assert_eq!(Snapshot::from_bytes(&words, 1).unwrap_err().to_string(), "Snapshot is corrupt - 4294967295 items don't fit in the last 0 bytes");
+
+        let mut strings = bytes[..20].to_vec();
+        strings.extend(u32::MAX.to_le_bytes());
+        assert!(matches!(Snapshot::from_bytes(&strings, 1), Err(SnapshotError::Corrupt(_))));
+    }
+}
@@ -0,0 +1,245 @@
+use std::{fs::File, io::BufReader, ops::ControlFlow, path::Path};
+
+use calamine::{open_workbook, Cell, Data, DeError, Range, RangeDeserializerBuilder, Reader, Xlsx, XlsxError};
+
+use crate::{columns::{ColumnError, ColumnMapping}, conversion::{ConversionError, ConversionWarning, LanguageMode}, interlinear::{InterlinearWordEntry, RawInterlinearEntry}, xlsx::{find_header, ReadError, COLUMNS, HEADER_SEARCH_ROWS}};
+
+/// Why a streamed row could not be read or converted
+#[derive(Debug)]
+pub enum StreamError {
+    /// The workbook could not be read, after which there are no more rows
+    Read(calamine::Error),
+    /// The row could not be converted, but the rows after it can still be read
+    Conversion(ConversionError),
+}
+
+impl std::fmt::Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(error) => write!(f, "{}", error),
+            Self::Conversion(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/// Receives each word of a workbook from [`RawEntryReader::visit`]
+pub trait WordVisitor {
+    /// A row that was converted, with the cells that had to be recovered
+    fn word(&mut self, word: InterlinearWordEntry, warnings: Vec<ConversionWarning>) -> ControlFlow<()>;
+
+    /// A row that could not be converted
+    fn error(&mut self, error: ConversionError) -> ControlFlow<()> {
+        let _ = error;
+        ControlFlow::Continue(())
+    }
+}
+
+/**
+Reads `bsb_tables.xlsx` one row at a time, instead of loading a whole worksheet like [`crate::xlsx::read_raw_entries`]
+
+Only the current row is kept in memory, besides the shared strings table, which calamine loads when the workbook is opened.
+- The header row is found the same way, within the first rows of the first sheet that has it
+- Rows without any cells are skipped
+*/
+pub struct RawEntryReader {
+    workbook: Xlsx<BufReader<File>>,
+    columns: ColumnMapping,
+}
+
+/// Where the rows of a sheet start
+struct Header {
+    sheet: String,
+    /// Absolute index of the header row
+    row: u32,
+    /// The absolute column index of each of [`COLUMNS`]
+    columns: Vec<usize>,
+}
+
+impl RawEntryReader {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, calamine::Error> {
+        Ok(Self { workbook: open_workbook(path)?, columns: ColumnMapping::default() })
+    }
+
+    /// Reads the columns of another release
+    pub fn with_columns(self, columns: ColumnMapping) -> Self {
+        Self { columns, ..self }
+    }
+
+    fn locate_header(&mut self) -> Result<Header, ReadError> {
+        let sheets = match &self.columns.sheet {
+            Some(sheet) => vec![sheet.clone()],
+            None => self.workbook.sheet_names(),
+        };
+
+        let mut closest: Option<ColumnError> = None;
+        for sheet in sheets {
+            let mut reader = self.workbook.worksheet_cells_reader(&sheet)?;
+            let rows = cell_rows(|| reader.next_cell())
+                .take(HEADER_SEARCH_ROWS)
+                .collect::<Result<Vec<_>, _>>()?;
+            match find_header(rows.iter().map(|(_, cells)| cells.as_slice()), &self.columns) {
+                Ok((index, columns)) => return Ok(Header { sheet, row: rows[index].0, columns }),
+                Err(error) => closest = Some(match closest {
+                    Some(closest) => closest.closest(error),
+                    None => error,
+                }),
+            }
+        }
+        Err(closest.unwrap_or_else(|| self.columns.not_found()))?
+    }
+
+    /// Every row below the header row
+    /// - The iterator ends after the first error
+    pub fn rows(&mut self) -> Result<impl Iterator<Item = Result<RawInterlinearEntry, calamine::Error>> + '_, ReadError> {
+        let header = self.locate_header()?;
+        let mut reader = self.workbook.worksheet_cells_reader(&header.sheet)?;
+        let mut failed = false;
+
+        let rows = cell_rows(move || reader.next_cell())
+            .skip_while(move |row| matches!(row, Ok((index, _)) if *index <= header.row))
+            .map_while(move |row| {
+                if failed {
+                    return None;
+                }
+                let entry = row.map_err(calamine::Error::from)
+                    .and_then(|(_, cells)| Ok(deserialize_row(&header.columns, &cells)?));
+                failed = entry.is_err();
+                Some(entry)
+            });
+        Ok(rows)
+    }
+
+    /// [`RawEntryReader::rows`], converted with [`InterlinearWordEntry::convert`]
+    pub fn words(&mut self, mode: LanguageMode) -> Result<impl Iterator<Item = Result<(InterlinearWordEntry, Vec<ConversionWarning>), StreamError>> + '_, ReadError> {
+        Ok(self.rows()?.map(move |raw| {
+            InterlinearWordEntry::convert(raw.map_err(StreamError::Read)?, mode).map_err(StreamError::Conversion)
+        }))
+    }
+
+    /// Calls the visitor with every word until it breaks, returning whether it did
+    pub fn visit(&mut self, mode: LanguageMode, visitor: &mut impl WordVisitor) -> Result<ControlFlow<()>, ReadError> {
+        for word in self.words(mode)? {
+            let flow = match word {
+                Ok((word, warnings)) => visitor.word(word, warnings),
+                Err(StreamError::Conversion(error)) => visitor.error(error),
+                Err(StreamError::Read(error)) => Err(error)?,
+            };
+            if flow.is_break() {
+                return Ok(flow);
+            }
+        }
+        Ok(ControlFlow::Continue(()))
+    }
+}
+
+/// Groups the cells of a sheet into `(row index, cells)`, where each cell is at its absolute column index
+/// - Cells are read in order, so a row is complete once a cell of the next row is read
+fn cell_rows<'a>(mut next_cell: impl FnMut() -> Result<Option<Cell<calamine::DataRef<'a>>>, XlsxError>) -> impl Iterator<Item = Result<(u32, Vec<Data>), XlsxError>> {
+    let mut pending: Option<Cell<Data>> = None;
+    let mut done = false;
+
+    std::iter::from_fn(move || {
+        let mut row: Option<(u32, Vec<Data>)> = None;
+        while !done {
+            let cell = match pending.take() {
+                Some(cell) => cell,
+                None => match next_cell() {
+                    Ok(Some(cell)) => Cell::new(cell.get_position(), Data::from(cell.get_value().clone())),
+                    Ok(None) => {
+                        done = true;
+                        break;
+                    },
+                    Err(error) => {
+                        done = true;
+                        return Some(Err(error));
+                    },
+                },
+            };
+            let (index, column) = cell.get_position();
+            if cell.get_value() == &Data::Empty {
+                continue;
+            }
+            if row.as_ref().is_some_and(|(current, _)| *current != index) {
+                pending = Some(cell);
+                break;
+            }
+            let (_, cells) = row.get_or_insert_with(|| (index, Vec::new()));
+            if cells.len() <= column as usize {
+                cells.resize(column as usize + 1, Data::Empty);
+            }
+            cells[column as usize] = cell.get_value().clone();
+        }
+        row.map(Ok)
+    })
+}
+
+/// Deserializes a single row, with the cell of each of [`COLUMNS`] at its index in `columns`
+fn deserialize_row(columns: &[usize], cells: &[Data]) -> Result<RawInterlinearEntry, DeError> {
+    let mut range = Range::new((0, 0), (1, COLUMNS.len() as u32 - 1));
+    for (index, ((field, _), column)) in COLUMNS.iter().zip(columns).enumerate() {
+        range.set_value((0, index as u32), Data::String(field.to_string()));
+        range.set_value((1, index as u32), cells.get(*column).cloned().unwrap_or(Data::Empty));
+    }
+    RangeDeserializerBuilder::with_deserialize_headers::<RawInterlinearEntry>()
+        .from_range(&range)?
+        .next()
+        .expect("the range has a row below the header")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{conversion::convert_entries_with, fixture::{fixture_path, raw_fixture}};
+
+    use super::*;
+
+    #[test]
+    fn rows() -> Result<(), ReadError> {
+        let mut reader = RawEntryReader::open(fixture_path())?;
+        let rows = reader.rows()?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(rows, raw_fixture());
+
+        // the header is found again for every pass
+        assert_eq!(reader.rows()?.take(2).collect::<Result<Vec<_>, _>>()?, rows[..2]);
+        Ok(())
+    }
+
+    #[test]
+    fn words() -> Result<(), Box<dyn std::error::Error>> {
+        let (words, errors, warnings) = convert_entries_with(raw_fixture(), LanguageMode::Infer);
+        let streamed = RawEntryReader::open(fixture_path())?
+            .words(LanguageMode::Infer)?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(streamed.iter().map(|(word, _)| word.clone()).collect::<Vec<_>>(), words);
+        assert!(streamed.iter().all(|(_, warnings)| warnings.is_empty()));
+        assert_eq!((errors.len(), warnings.len()), (0, 0));
+        Ok(())
+    }
+
+    struct Matthew {
+        words: Vec<InterlinearWordEntry>,
+    }
+
+    impl WordVisitor for Matthew {
+        fn word(&mut self, word: InterlinearWordEntry, _: Vec<ConversionWarning>) -> ControlFlow<()> {
+            if word.verse_id < 23146 {
+                return ControlFlow::Continue(());
+            }
+            self.words.push(word);
+            match self.words.len() {
+                3 => ControlFlow::Break(()),
+                _ => ControlFlow::Continue(()),
+            }
+        }
+    }
+
+    #[test]
+    fn visit() -> Result<(), ReadError> {
+        let mut visitor = Matthew { words: vec![] };
+        let flow = RawEntryReader::open(fixture_path())?.visit(LanguageMode::Strict, &mut visitor)?;
+        assert_eq!(flow, ControlFlow::Break(()));
+        assert_eq!(visitor.words.iter().map(|word| word.transliteration.as_str()).collect::<Vec<_>>(), ["Biblos", "geneseōs", "Iēsou"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use std::{fmt::Display, path::Path};
+
+use calamine::{open_workbook, Data, DeError, Range, RangeDeserializerBuilder, Reader, Xlsx, XlsxError};
+
+use crate::{columns::{ColumnError, ColumnMapping}, interlinear::RawInterlinearEntry};
+
+/// `(field, Excel column title)` for every field of [`RawInterlinearEntry`]
+/// - `"Verse"` and `"Parsing"` both appear twice, so the n-th field with a title is read from the n-th column with that title
+pub const COLUMNS: &[(&str, &str)] = &[
+    ("hebrew_sort", "Heb Sort"),
+    ("greek_sort", "Greek Sort"),
+    ("bsb_sort", "BSB Sort"),
+    ("verse_id", "Verse"),
+    ("language", "Language"),
+    ("text_1", "WLC / Nestle Base TR RP WH NE NA SBL"),
+    ("text_2", "WLC / Nestle Base {TR} ⧼RP⧽ (WH) 〈NE〉 [NA] ‹SBL› [[ECM]]"),
+    ("transliteration", "Translit"),
+    ("parsing_code", "Parsing"),
+    ("parsing", "Parsing"),
+    ("strongs_hebrew", "Str Heb"),
+    ("strongs_greek", "Str Grk"),
+    ("verse", "Verse"),
+    ("heading", "Hdg"),
+    ("crossref", "Crossref"),
+    ("paragraph", "Par"),
+    ("start_quote", "“"),
+    ("english", " BSB version "),
+    ("puncutation", "pnc"),
+    ("end_quote", "”"),
+    ("footnotes", "footnotes"),
+    ("end_text", "End text"),
+];
+
+/// The header row is expected within the first few rows of the sheet (there may be a title row above it)
+pub(crate) const HEADER_SEARCH_ROWS: usize = 10;
+
+/// Why the rows of a workbook, or of a TSV or CSV file, could not be read
+#[derive(Debug)]
+pub enum ReadError {
+    /// The file could not be read, or a row could not be deserialized
+    Workbook(calamine::Error),
+    /// No sheet has a header row with every mapped column
+    Columns(ColumnError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Workbook(error) => write!(f, "{}", error),
+            Self::Columns(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+impl From<calamine::Error> for ReadError {
+    fn from(error: calamine::Error) -> Self {
+        Self::Workbook(error)
+    }
+}
+
+impl From<XlsxError> for ReadError {
+    fn from(error: XlsxError) -> Self {
+        Self::Workbook(error.into())
+    }
+}
+
+impl From<DeError> for ReadError {
+    fn from(error: DeError) -> Self {
+        Self::Workbook(error.into())
+    }
+}
+
+impl From<std::io::Error> for ReadError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Workbook(error.into())
+    }
+}
+
+impl From<ColumnError> for ReadError {
+    fn from(error: ColumnError) -> Self {
+        Self::Columns(error)
+    }
+}
+
+/// Opens `bsb_tables.xlsx` and reads every row of the first sheet containing the header row
+pub fn read_raw_entries(path: impl AsRef<Path>) -> Result<Vec<RawInterlinearEntry>, ReadError> {
+    read_raw_entries_with(path, &ColumnMapping::default())
+}
+
+/// [`read_raw_entries`], with the columns of another release
+/// - Only reads [`ColumnMapping::sheet`] when it is set
+pub fn read_raw_entries_with(path: impl AsRef<Path>, columns: &ColumnMapping) -> Result<Vec<RawInterlinearEntry>, ReadError> {
+    let mut workbook: Xlsx<_> = open_workbook(path)?;
+    let sheets = match &columns.sheet {
+        Some(sheet) => vec![sheet.clone()],
+        None => workbook.sheet_names(),
+    };
+
+    let mut closest: Option<ColumnError> = None;
+    for sheet in sheets {
+        let mut range = workbook.worksheet_range(&sheet)?;
+        match raw_entries_from_range(&mut range, columns) {
+            Err(ReadError::Columns(error)) => closest = Some(match closest {
+                Some(closest) => closest.closest(error),
+                None => error,
+            }),
+            result => return result,
+        }
+    }
+
+    Err(closest.unwrap_or_else(|| columns.not_found()))?
+}
+
+/// Deserializes every row below the header row
+/// - The header cells are renamed to the field names of [`RawInterlinearEntry`] so that duplicate titles can be told apart
+pub fn raw_entries_from_range(range: &mut Range<Data>, columns: &ColumnMapping) -> Result<Vec<RawInterlinearEntry>, ReadError> {
+    let (header_row, column_indexes) = find_header(range.rows().take(HEADER_SEARCH_ROWS), columns)?;
+    let (start_row, start_column) = range.start().unwrap_or((0, 0));
+    let header_row = start_row + header_row as u32;
+
+    for ((field, _), column) in COLUMNS.iter().zip(column_indexes) {
+        range.set_value((header_row, start_column + column as u32), Data::String(field.to_string()));
+    }
+
+    // only copy the range when there is something above the header row
+    let sub_range;
+    let range = if header_row == start_row {
+        &*range
+    } else {
+        sub_range = range.range((header_row, start_column), range.end().unwrap_or((header_row, start_column)));
+        &sub_range
+    };
+
+    Ok(RangeDeserializerBuilder::with_deserialize_headers::<RawInterlinearEntry>()
+        .from_range(range)?
+        .collect::<Result<_, _>>()?)
+}
+
+/// Returns the index of the header row among `rows` and the column index of each of [`COLUMNS`]
+/// - Otherwise, the error of the row that had the most columns
+pub(crate) fn find_header<'a>(rows: impl IntoIterator<Item = &'a [Data]>, columns: &ColumnMapping) -> Result<(usize, Vec<usize>), ColumnError> {
+    let mut closest: Option<ColumnError> = None;
+
+    for (row_index, row) in rows.into_iter().enumerate() {
+        match columns.locate(row) {
+            Ok(indexes) => return Ok((row_index, indexes)),
+            Err(error) => closest = Some(match closest {
+                Some(closest) => closest.closest(error),
+                None => error,
+            }),
+        }
+    }
+
+    Err(closest.unwrap_or_else(|| columns.not_found()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fixture::fixture_path;
+
+    use super::*;
+
+    #[test]
+    fn fixture() -> Result<(), ReadError> {
+        let entries = read_raw_entries(fixture_path())?;
+        assert_eq!(entries.len(), 62);
+
+        let first = &entries[0];
+        assert_eq!(first.hebrew_sort, 1);
+        assert_eq!(first.greek_sort, 0);
+        assert_eq!(first.bsb_sort, 1);
+        assert_eq!(first.verse_id, 1);
+        assert_eq!(first.language, "Hebrew");
+        assert_eq!(first.transliteration.as_deref(), Some("bə·rê·šîṯ"));
+        assert_eq!(first.parsing_code.as_deref(), Some("Prep-b | N-fs"));
+        assert_eq!(first.parsing.as_deref(), Some("Preposition-b | Noun - feminine singular"));
+        assert_eq!(first.strongs_hebrew, Some(7225));
+        assert_eq!(first.strongs_greek, None);
+        assert_eq!(first.verse.as_deref(), Some("Genesis 1:1"));
+        assert_eq!(first.heading.as_deref(), Some("<p class=|hdg|>The Creation"));
+        assert_eq!(first.english.as_deref(), Some(" In the beginning "));
+        assert_eq!(first.puncutation, None);
+
+        // the verse reference is only on the first word of each verse
+        assert_eq!(entries[1].verse, None);
+
+        let matthew = entries.iter().find(|entry| entry.verse.as_deref() == Some("Matthew 1:1")).unwrap();
+        assert_eq!(matthew.verse_id, 23146);
+        assert_eq!(matthew.language, "Greek");
+        assert_eq!(matthew.text_1.as_deref(), Some("Βίβλος"));
+        assert_eq!(matthew.parsing_code.as_deref(), Some("N-NFS"));
+        assert_eq!(matthew.parsing.as_deref(), Some("Noun - Nominative Feminine Singular"));
+        assert_eq!(matthew.strongs_greek, Some(976));
+        assert_eq!(matthew.english.as_deref(), Some(" [This is the] record "));
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_column() {
+        let mut range = Range::new((0, 0), (1, 1));
+        range.set_value((0, 0), Data::String("Heb Sort".to_string()));
+        range.set_value((0, 1), Data::String("Greek Sort".to_string()));
+        range.set_value((1, 0), Data::Float(1.0));
+        range.set_value((1, 1), Data::Float(0.0));
+
+        match raw_entries_from_range(&mut range, &ColumnMapping::default()) {
+            Err(ReadError::Columns(error)) => {
+                assert_eq!(error.missing.len(), COLUMNS.len() - 2);
+                assert_eq!(error.missing[0], "BSB Sort");
+                assert_eq!(error.unexpected, Vec::<String>::new());
+            },
+            other => panic!("Expected a missing header, got {:?}", other),
+        }
+    }
+}